@@ -1,53 +1,331 @@
-use chrono_probe::input::InputSet;
+use std::ops::Deref;
 
-use crate::input::InputString;
+use chrono_probe::input::{Input, InputSet};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::{error::Error, input::InputString, matching::TwoWay, simd::PERIOD_NAIVE_SIMD};
 
 /// Representation of an algorithm &\[[u8]] -> [usize]
-pub struct Algorithm {
+///
+/// The algorithm works on [`InputString`]s by default, but it can be defined on any other
+/// [`Input`], e.g. on [`UnicodeString`](crate::input::UnicodeString)s.
+pub struct Algorithm<I = InputString> {
     /// The name of the algorithm
     pub name: &'static str,
     /// The function implemented by the algorithm
-    pub function: fn(&InputString) -> usize,
+    pub function: fn(&I) -> usize,
 }
 
-impl Algorithm {
+impl<I: Input> Algorithm<I> {
     /// Compute empirically the expected value of the algorithm with the given input set.
-    pub fn expected_value(&self, input_set: &InputSet<InputString>) -> f64 {
-        let flattened_input_set = input_set
+    pub fn expected_value(&self, input_set: &InputSet<I>) -> f64 {
+        mean(&self.evaluate(input_set))
+    }
+
+    /// Applies the algorithm to every input of the set, keeping the structure of the set: the
+    /// result at `[i][j]` is the one of the input at `input_set.inputs[i][j]`.
+    pub fn evaluate(&self, input_set: &InputSet<I>) -> Vec<Vec<usize>> {
+        input_set
             .inputs
             .iter()
-            .flatten()
-            .collect::<Vec<&InputString>>();
-        let size = flattened_input_set.len();
-        let mut sum = 0.0;
-        for input in flattened_input_set {
-            sum += (self.function)(input) as f64;
+            .map(|inputs| inputs.iter().map(self.function).collect())
+            .collect()
+    }
+
+    /// Applies the algorithm to the given input, returning `None` if the input is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractional_period::{algorithms::PERIOD_SMART, input::InputString};
+    ///
+    /// assert_eq!(PERIOD_SMART.checked(&InputString(vec![])), None);
+    /// assert_eq!(PERIOD_SMART.checked(&InputString(b"abab".to_vec())), Some(2));
+    /// ```
+    pub fn checked(&self, input: &I) -> Option<usize> {
+        if input.get_size() == 0 {
+            None
+        } else {
+            Some((self.function)(input))
+        }
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<I: Input + Sync> Algorithm<I> {
+    /// Parallel version of [`Algorithm::expected_value`], returning exactly the same value.
+    pub fn par_expected_value(&self, input_set: &InputSet<I>) -> f64 {
+        mean(&self.par_evaluate(input_set))
+    }
+
+    /// Parallel version of [`Algorithm::evaluate`]: the inputs are distributed among the threads
+    /// of the rayon pool, but the results are in the same order as in the sequential version.
+    pub fn par_evaluate(&self, input_set: &InputSet<I>) -> Vec<Vec<usize>> {
+        input_set
+            .inputs
+            .par_iter()
+            .map(|inputs| inputs.par_iter().map(self.function).collect())
+            .collect()
+    }
+}
+
+/// The mean of the results of an algorithm over an input set, summed in the order of the set so
+/// that the sequential and parallel evaluations agree.
+fn mean(results: &[Vec<usize>]) -> f64 {
+    let flattened_results = results.iter().flatten().collect::<Vec<_>>();
+    let size = flattened_results.len();
+    let mut sum = 0.0;
+    for &result in flattened_results {
+        sum += result as f64;
+    }
+    sum / size as f64
+}
+
+/// A stateless function computing the period of an [`InputString`]
+pub type PeriodFunction = fn(&InputString) -> usize;
+
+/// An algorithm for finding the period of an [`InputString`].
+///
+/// Unlike [`Algorithm`], an implementation can carry configuration or state between calls, e.g.
+/// a buffer reused across the strings of an input set.
+pub trait PeriodAlgorithm {
+    /// The name of the algorithm
+    fn name(&self) -> &str;
+
+    /// Computes the period of the given string.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The string to be analyzed
+    fn period(&mut self, s: &InputString) -> usize;
+
+    /// Computes the period of the given string, returning `None` if the string is empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The string to be analyzed
+    fn checked(&mut self, s: &InputString) -> Option<usize> {
+        if s.is_empty() {
+            None
+        } else {
+            Some(self.period(s))
+        }
+    }
+
+    /// Compute empirically the expected value of the algorithm with the given input set.
+    fn expected_value(&mut self, input_set: &InputSet<InputString>) -> f64 {
+        let results = input_set
+            .inputs
+            .iter()
+            .map(|inputs| inputs.iter().map(|s| self.period(s)).collect())
+            .collect::<Vec<_>>();
+        mean(&results)
+    }
+
+    /// The stateless function implementing the algorithm, if any.
+    ///
+    /// Only algorithms providing one can be measured with
+    /// [`measure`](chrono_probe::measurements::measure).
+    fn function(&self) -> Option<PeriodFunction> {
+        None
+    }
+}
+
+impl PeriodAlgorithm for Algorithm {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn period(&mut self, s: &InputString) -> usize {
+        (self.function)(s)
+    }
+
+    fn function(&self) -> Option<PeriodFunction> {
+        Some(self.function)
+    }
+}
+
+/// A collection of period algorithms, selectable by name.
+///
+/// # Examples
+///
+/// ```
+/// use fractional_period::{algorithms::Registry, input::InputString};
+///
+/// let mut registry = Registry::builtin().select(&["period smart", "period z"]).unwrap();
+/// for algorithm in registry.iter_mut() {
+///     assert_eq!(algorithm.period(&InputString(b"abcabcab".to_vec())), 3);
+/// }
+/// ```
+#[derive(Default)]
+pub struct Registry {
+    algorithms: Vec<Box<dyn PeriodAlgorithm>>,
+}
+
+impl Registry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a registry containing all the predefined period algorithms of the crate.
+    pub fn builtin() -> Self {
+        let mut registry = Self::new();
+        for algorithm in [
+            PERIOD_NAIVE1,
+            PERIOD_NAIVE2,
+            PERIOD_SMART,
+            PERIOD_PREFIXES,
+            PERIOD_Z,
+            PERIOD_CONSTANT_SPACE,
+            PERIOD_NAIVE_SIMD,
+        ] {
+            registry.register(algorithm);
+        }
+        registry.register(PeriodSolver::new());
+        registry
+    }
+
+    /// Adds an algorithm to the registry.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an algorithm with the same name is already registered.
+    pub fn register(&mut self, algorithm: impl PeriodAlgorithm + 'static) {
+        self.try_register(algorithm)
+            .unwrap_or_else(|error| panic!("{error}"));
+    }
+
+    /// Adds an algorithm to the registry, returning an error if an algorithm with the same name is
+    /// already registered.
+    pub fn try_register(&mut self, algorithm: impl PeriodAlgorithm + 'static) -> Result<(), Error> {
+        if self.get(algorithm.name()).is_some() {
+            return Err(Error::DuplicateAlgorithm(algorithm.name().to_string()));
         }
-        sum / size as f64
+        self.algorithms.push(Box::new(algorithm));
+        Ok(())
+    }
+
+    /// Keeps only the algorithms with the given names, in the given order.
+    ///
+    /// # Arguments
+    ///
+    /// * `names` - The names of the algorithms to be kept
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::DuplicateAlgorithm`] if a name is given more than once
+    /// * [`Error::UnknownAlgorithm`] if no algorithm with a given name is registered
+    pub fn select<S: AsRef<str>>(mut self, names: &[S]) -> Result<Self, Error> {
+        let mut selected = Self::new();
+        for name in names {
+            let name = name.as_ref();
+            if selected.get(name).is_some() {
+                return Err(Error::DuplicateAlgorithm(name.to_string()));
+            }
+            let index = self
+                .algorithms
+                .iter()
+                .position(|algorithm| algorithm.name() == name)
+                .ok_or_else(|| Error::UnknownAlgorithm(name.to_string()))?;
+            selected.algorithms.push(self.algorithms.remove(index));
+        }
+        Ok(selected)
+    }
+
+    /// Returns the algorithm with the given name, if registered.
+    pub fn get(&self, name: &str) -> Option<&dyn PeriodAlgorithm> {
+        self.iter().find(|algorithm| algorithm.name() == name)
+    }
+
+    /// Returns the algorithm with the given name mutably, if registered.
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut dyn PeriodAlgorithm> {
+        self.iter_mut().find(|algorithm| algorithm.name() == name)
+    }
+
+    /// Returns the names of the registered algorithms.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.iter().map(|algorithm| algorithm.name())
+    }
+
+    /// Returns the registered algorithms which can be measured, paired with their names, in the
+    /// form expected by [`measure`](chrono_probe::measurements::measure).
+    pub fn measurable(&self) -> Vec<(PeriodFunction, &str)> {
+        self.iter()
+            .filter_map(|algorithm| Some((algorithm.function()?, algorithm.name())))
+            .collect()
+    }
+
+    /// Returns an iterator over the registered algorithms.
+    pub fn iter(&self) -> impl Iterator<Item = &dyn PeriodAlgorithm> {
+        self.algorithms.iter().map(|algorithm| algorithm.as_ref())
+    }
+
+    /// Returns a mutable iterator over the registered algorithms.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut dyn PeriodAlgorithm> {
+        self.algorithms
+            .iter_mut()
+            .map(|algorithm| algorithm.as_mut() as &mut dyn PeriodAlgorithm)
+    }
+
+    /// Returns the number of registered algorithms.
+    pub fn len(&self) -> usize {
+        self.algorithms.len()
+    }
+
+    /// Returns `true` if no algorithm is registered.
+    pub fn is_empty(&self) -> bool {
+        self.algorithms.is_empty()
     }
 }
 
-// Some predefined algorithms for finding the period of a string:
+// Some predefined algorithms for finding the period of a string. The period algorithms are
+// generic over the element type of the string, the predefined algorithms apply them to the bytes
+// of an `InputString`. By convention all of them return 0 as the period of the empty string,
+// see `Algorithm::checked` for a variant returning `None` instead:
 
 /// The naive algorithm for finding the period of a string.
 /// Time complexity: O(n<sup>2</sup>)
 pub const PERIOD_NAIVE1: Algorithm = Algorithm {
     name: "period naive 1",
-    function: period_naive1,
+    function: |s| period_naive1(&s[..]),
 };
 
 /// Variation of the naive algorithm for finding the period of a string
 /// Time complexity: O(n<sup>2</sup>)
 pub const PERIOD_NAIVE2: Algorithm = Algorithm {
     name: "period naive 2",
-    function: period_naive2,
+    function: |s| period_naive2(&s[..]),
 };
 
 /// The smart algorithm for finding the period of a string
 /// Time complexity: Θ(n<sup>2</sup>)
 pub const PERIOD_SMART: Algorithm = Algorithm {
     name: "period smart",
-    function: period_smart,
+    function: |s| period_smart(&s[..]),
+};
+
+/// Computes the period of every prefix of the string and returns the one of the entire string.
+/// Time complexity: Θ(n)
+pub const PERIOD_PREFIXES: Algorithm = Algorithm {
+    name: "period prefixes",
+    function: period_prefixes,
+};
+
+/// Algorithm for finding the period of a string based on the Z-array
+/// Time complexity: Θ(n)
+pub const PERIOD_Z: Algorithm = Algorithm {
+    name: "period z",
+    function: |s| period_z(&s[..]),
+};
+
+/// Algorithm for finding the period of a string in constant extra space, based on the critical
+/// factorization of the string
+/// Time complexity: Θ(n)
+pub const PERIOD_CONSTANT_SPACE: Algorithm = Algorithm {
+    name: "period constant space",
+    function: period_constant_space,
 };
 
 /// The naive algorithm for finding the period of a string.
@@ -55,7 +333,7 @@ pub const PERIOD_SMART: Algorithm = Algorithm {
 /// # Arguments
 ///
 /// * `s` - The string to be analyzed
-pub fn period_naive1(s: &InputString) -> usize {
+pub fn period_naive1<T: Eq>(s: &[T]) -> usize {
     let n = s.len();
 
     'outer: for i in 1..n {
@@ -74,7 +352,7 @@ pub fn period_naive1(s: &InputString) -> usize {
 /// # Arguments
 ///
 /// * `s` - The string to be analyzed
-pub fn period_naive2(s: &InputString) -> usize {
+pub fn period_naive2<T: Eq>(s: &[T]) -> usize {
     let n = s.len();
     for i in 1..n {
         if s[..n - i] == s[i..] {
@@ -89,11 +367,204 @@ pub fn period_naive2(s: &InputString) -> usize {
 /// # Arguments
 ///
 /// * `s` - The string to be analyzed
-pub fn period_smart(s: &InputString) -> usize {
+///
+/// # Examples
+///
+/// ```
+/// use fractional_period::algorithms::period_smart;
+///
+/// let events = ["open", "read", "close", "open", "read"];
+/// assert_eq!(period_smart(&events), 3);
+/// ```
+pub fn period_smart<T: Eq>(s: &[T]) -> usize {
+    let size = s.len();
+    // by convention the period of the empty string is 0
+    if size == 0 {
+        return 0;
+    }
+    let b = border_array(s);
+    // the maximum border of the entire string
+    let max_border = b[size - 1];
+    // The minimum fractional period is the length of the string minus the maximum border
+    size - max_border
+}
+
+/// The smart algorithm for finding the period of a string, failing if the string is empty
+///
+/// # Arguments
+///
+/// * `s` - The string to be analyzed
+///
+/// # Errors
+///
+/// * [`Error::EmptyString`] if the string is empty
+pub fn try_period_smart<T: Eq>(s: &[T]) -> Result<usize, Error> {
+    if s.is_empty() {
+        return Err(Error::EmptyString);
+    }
+    Ok(period_smart(s))
+}
+
+/// Computes the minimum fractional period of every prefix of a string in linear time.
+///
+/// The element at index `i` is the period of `s[0..=i]`, derived from the border array as
+/// `i + 1 - b[i]`.
+///
+/// # Arguments
+///
+/// * `s` - The string to be analyzed
+///
+/// # Examples
+///
+/// ```
+/// use fractional_period::{algorithms::prefix_periods, input::InputString};
+///
+/// let s: InputString = "abaab".try_into().unwrap();
+/// assert_eq!(prefix_periods(&s[..]), vec![1, 2, 2, 3, 3]);
+/// ```
+pub fn prefix_periods<T: Eq>(s: &[T]) -> Vec<usize> {
+    border_array(s)
+        .into_iter()
+        .enumerate()
+        .map(|(i, border)| i + 1 - border)
+        .collect()
+}
+
+/// Wrapper of [`prefix_periods`] with the signature of an [`Algorithm`], it returns the period of
+/// the entire string.
+///
+/// # Arguments
+///
+/// * `s` - The string to be analyzed
+pub fn period_prefixes(s: &InputString) -> usize {
+    prefix_periods(&s[..]).last().copied().unwrap_or(0)
+}
+
+/// Computes the period of many strings with the border algorithm of [`period_smart`], reusing the
+/// same buffer for the border array instead of allocating a new one for every string.
+///
+/// # Examples
+///
+/// ```
+/// use fractional_period::algorithms::PeriodSolver;
+///
+/// let mut solver = PeriodSolver::new();
+/// assert_eq!(solver.period(b"abcabcab"), 3);
+/// assert_eq!(solver.period(b"aba"), 2);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct PeriodSolver {
+    borders: Vec<usize>,
+}
+
+impl PeriodSolver {
+    /// Creates a solver with an empty buffer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a solver whose buffer can hold the border array of a string of length `n` without
+    /// reallocating.
+    pub fn with_capacity(n: usize) -> Self {
+        PeriodSolver {
+            borders: Vec::with_capacity(n),
+        }
+    }
+
+    /// Computes the period of the string, returning 0 for the empty string.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The string to be analyzed
+    pub fn period<T: Eq>(&mut self, s: &[T]) -> usize {
+        self.border_array(s)
+            .last()
+            .map_or(0, |&max_border| s.len() - max_border)
+    }
+
+    /// Computes the periods of all the strings of the input set, keeping the structure of the set.
+    pub fn evaluate(&mut self, input_set: &InputSet<InputString>) -> Vec<Vec<usize>> {
+        input_set
+            .inputs
+            .iter()
+            .map(|inputs| inputs.iter().map(|s| self.period(&s[..])).collect())
+            .collect()
+    }
+
+    /// Computes the periods of all the strings of the input set in parallel, keeping the
+    /// structure of the set. Every thread of the rayon pool uses its own solver.
+    #[cfg(feature = "parallel")]
+    pub fn par_evaluate(input_set: &InputSet<InputString>) -> Vec<Vec<usize>> {
+        input_set
+            .inputs
+            .par_iter()
+            .map(|inputs| {
+                inputs
+                    .par_iter()
+                    .map_init(PeriodSolver::new, |solver, s| solver.period(&s[..]))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Parallel version of [`PeriodAlgorithm::expected_value`] for the solver, returning exactly
+    /// the same value.
+    #[cfg(feature = "parallel")]
+    pub fn par_expected_value(input_set: &InputSet<InputString>) -> f64 {
+        mean(&Self::par_evaluate(input_set))
+    }
+
+    /// Computes the border array of the string, see [`border_array`].
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The string to be analyzed
+    pub fn border_array<T: Eq>(&mut self, s: &[T]) -> &[usize] {
+        fill_border_array(s, &mut self.borders);
+        &self.borders
+    }
+}
+
+impl PeriodAlgorithm for PeriodSolver {
+    fn name(&self) -> &str {
+        "period solver"
+    }
+
+    fn period(&mut self, s: &InputString) -> usize {
+        PeriodSolver::period(self, &s[..])
+    }
+}
+
+/// Computes the border array (also known as failure function) of a string in linear time.
+///
+/// The element at index `i` is the length of the maximum border of `s[0..=i]`, that is the
+/// longest proper prefix of `s[0..=i]` that is also a suffix of it.
+///
+/// # Arguments
+///
+/// * `s` - The string to be analyzed
+///
+/// # Examples
+///
+/// ```
+/// use fractional_period::{algorithms::border_array, input::InputString};
+///
+/// let s: InputString = "abaab".try_into().unwrap();
+/// assert_eq!(border_array(&s[..]), vec![0, 0, 1, 1, 2]);
+/// ```
+pub fn border_array<T: Eq>(s: &[T]) -> Vec<usize> {
+    let mut b = Vec::new();
+    fill_border_array(s, &mut b);
+    b
+}
+
+/// Computes the border array of a string into the given buffer, reusing its allocation.
+fn fill_border_array<T: Eq>(s: &[T], b: &mut Vec<usize>) {
     let size = s.len();
 
-    // b[i] represents the maximum edge length of s[0..i]
-    let mut b = vec![0; size];
+    // b[i] represents the maximum edge length of s[0..=i]
+    b.clear();
+    b.resize(size, 0);
 
     // current maximum edge length
     let mut x;
@@ -117,8 +588,608 @@ pub fn period_smart(s: &InputString) -> usize {
         // update b[i]
         b[i] = x;
     }
-    // the maximum border of the entire string
-    let max_border = b[size - 1];
-    // The minimum fractional period is the length of the string minus the maximum border
-    size - max_border
+}
+
+/// Algorithm for finding the period of a string based on the Z-array: the period is the smallest
+/// `i` such that the suffix starting at `i` is also a prefix of the string.
+///
+/// # Arguments
+///
+/// * `s` - The string to be analyzed
+pub fn period_z<T: Eq>(s: &[T]) -> usize {
+    let n = s.len();
+    let z = z_array(s);
+    (1..n).find(|&i| i + z[i] == n).unwrap_or(n)
+}
+
+/// Computes the Z-array of a string in linear time.
+///
+/// The element at index `i` is the length of the longest common prefix between `s` and
+/// `s[i..]`. By convention the element at index 0 is the length of the string.
+///
+/// # Arguments
+///
+/// * `s` - The string to be analyzed
+///
+/// # Examples
+///
+/// ```
+/// use fractional_period::{algorithms::z_array, input::InputString};
+///
+/// let s: InputString = "abaab".try_into().unwrap();
+/// assert_eq!(z_array(&s[..]), vec![5, 0, 1, 2, 0]);
+/// ```
+pub fn z_array<T: Eq>(s: &[T]) -> Vec<usize> {
+    let n = s.len();
+    let mut z = vec![0; n];
+    if n == 0 {
+        return z;
+    }
+    z[0] = n;
+
+    // [left, right) is the rightmost window found so far that matches a prefix of s
+    let mut left = 0;
+    let mut right = 0;
+    for i in 1..n {
+        // inside the window the values of the prefix can be reused
+        if i < right {
+            z[i] = (right - i).min(z[i - left]);
+        }
+        // extend the match with explicit comparisons
+        while i + z[i] < n && s[z[i]] == s[i + z[i]] {
+            z[i] += 1;
+        }
+        if i + z[i] > right {
+            left = i;
+            right = i + z[i];
+        }
+    }
+    z
+}
+
+/// Algorithm for finding the period of a string in linear time and constant extra space.
+///
+/// The period is the length of the string minus the length of its maximum border. The borders are
+/// searched by decreasing scales: a border of length `b`, with `L ≤ b < 2L`, starts with the
+/// prefix `s[0..L]`, so its candidate positions are the occurrences of `s[0..L]` in the last
+/// `2L - 1` characters of the string. These occurrences are found with the Two-Way string matching
+/// algorithm, which only needs the critical factorization of `s[0..L]` (computed through maximal
+/// suffixes) and a constant number of variables. Each candidate is then verified, exploiting the
+/// periodicity of `s[0..L]` when it is highly periodic, so that every scale costs O(L).
+///
+/// # Arguments
+///
+/// * `s` - The string to be analyzed
+pub fn period_constant_space(s: &InputString) -> usize {
+    let n = s.len();
+    if n < 2 {
+        return n;
+    }
+
+    // the largest power of two smaller than n
+    let mut scale = 1 << (usize::BITS - 1 - (n - 1).leading_zeros());
+    while scale > 0 {
+        if let Some(border) = longest_border_in_scale(s, scale) {
+            return n - border;
+        }
+        scale /= 2;
+    }
+    n
+}
+
+/// Returns the length of the longest border `b` of `s` such that `scale ≤ b < 2 * scale`, if any.
+fn longest_border_in_scale(s: &[u8], scale: usize) -> Option<usize> {
+    let n = s.len();
+    let high = (2 * scale).min(n);
+    let prefix = &s[..scale];
+
+    // a border of length b starts at position n - b, with n - high < n - b <= n - scale
+    let offset = n - high + 1;
+    let searcher = TwoWay::new(prefix);
+    let mut occurrences = searcher.find_all(&s[offset..]).map(|i| i + offset);
+
+    if searcher.is_periodic() && 2 * searcher.period() <= scale {
+        let period = searcher.period();
+
+        // length of the longest prefix of s (up to high) having the same period of the prefix
+        let mut prefix_run = scale;
+        while prefix_run < high && s[prefix_run] == s[prefix_run - period] {
+            prefix_run += 1;
+        }
+
+        // end of the periodic run starting at the last verified occurrence
+        let mut run_end = 0;
+        for start in occurrences {
+            let border = n - start;
+            if start + scale > run_end {
+                run_end = start + scale;
+                while run_end < n && s[run_end] == s[run_end - period] {
+                    run_end += 1;
+                }
+            }
+            // s[0..] and s[start..] agree as long as both of them keep the period
+            let agreement = prefix_run.min(run_end - start);
+            if agreement >= border {
+                return Some(border);
+            }
+            if prefix_run == run_end - start && s[prefix_run..border] == s[run_end..] {
+                return Some(border);
+            }
+        }
+        None
+    } else {
+        // the occurrences are more than scale / 2 apart, so there are at most four of them
+        occurrences
+            .find(|&start| s[scale..n - start] == s[start + scale..])
+            .map(|start| n - start)
+    }
+}
+
+/// The border array of a string, with queries on the borders of its prefixes.
+///
+/// Every query takes the index `i` of the last character of the prefix, so it refers to the
+/// prefix `s[0..=i]`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BorderArray(Vec<usize>);
+
+impl BorderArray {
+    /// Computes the border array of the given string in linear time.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The string to be analyzed
+    pub fn new<T: Eq>(s: &[T]) -> Self {
+        BorderArray(border_array(s))
+    }
+
+    /// Returns the length of the longest border of `s[0..=i]`.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if `i` is out of bounds
+    pub fn longest_border(&self, i: usize) -> usize {
+        self.0[i]
+    }
+
+    /// Returns an iterator over the lengths of all the non-empty borders of `s[0..=i]`, from the
+    /// longest to the shortest.
+    ///
+    /// The borders are found by following the border chain: the longest border of a border is
+    /// the next longest border of the prefix.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if `i` is out of bounds
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractional_period::{algorithms::BorderArray, input::InputString};
+    ///
+    /// let s: InputString = "abaababa".try_into().unwrap();
+    /// let borders = BorderArray::new(&s[..]);
+    /// assert_eq!(borders.borders(7).collect::<Vec<_>>(), vec![3, 1]);
+    /// ```
+    pub fn borders(&self, i: usize) -> Borders<'_> {
+        Borders {
+            border_array: &self.0,
+            current: self.0[i],
+        }
+    }
+
+    /// Returns the minimum fractional period of `s[0..=i]`.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if `i` is out of bounds
+    pub fn period(&self, i: usize) -> usize {
+        i + 1 - self.0[i]
+    }
+
+    /// Consumes the struct and returns the underlying vector.
+    pub fn into_inner(self) -> Vec<usize> {
+        self.0
+    }
+}
+
+impl Deref for BorderArray {
+    type Target = Vec<usize>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Iterator over the border chain of a prefix, created by [`BorderArray::borders`].
+pub struct Borders<'a> {
+    border_array: &'a [usize],
+    current: usize,
+}
+
+impl Iterator for Borders<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current == 0 {
+            return None;
+        }
+        let border = self.current;
+        self.current = self.border_array[border - 1];
+        Some(border)
+    }
+}
+
+/// Computes all the periods `p` (with `1 ≤ p ≤ n`) of a string by walking the border chain of the
+/// entire string: every border of length `x` corresponds to the period `n - x`.
+///
+/// The periods are stored compactly as a list of arithmetic progressions. By the Fine–Wilf
+/// theorem the periods of a string can be grouped in O(log n) progressions, so strings with a lot
+/// of periods (e.g. `aaa...a`) don't require a huge amount of memory.
+///
+/// # Arguments
+///
+/// * `s` - The string to be analyzed
+///
+/// # Examples
+///
+/// ```
+/// use fractional_period::{algorithms::all_periods, input::InputString};
+///
+/// let s: InputString = "abaababaab".try_into().unwrap();
+/// assert_eq!(all_periods(&s[..]).iter().collect::<Vec<_>>(), vec![5, 8, 10]);
+/// ```
+pub fn all_periods<T: Eq>(s: &[T]) -> Periods {
+    let size = s.len();
+    let mut periods = Periods {
+        progressions: Vec::new(),
+    };
+    if size == 0 {
+        return periods;
+    }
+
+    // the borders are visited from the longest to the shortest, hence the periods are increasing
+    let borders = BorderArray::new(s);
+    for border in borders.borders(size - 1).chain(std::iter::once(0)) {
+        periods.push(size - border);
+    }
+    periods
+}
+
+/// The set of all the periods of a string, created by [`all_periods`].
+///
+/// The periods are represented as a sequence of arithmetic progressions in increasing order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Periods {
+    progressions: Vec<Progression>,
+}
+
+/// An arithmetic progression of periods: `first, first + difference, ..., first + (count - 1) *
+/// difference`.
+///
+/// A progression with a single element has `difference` equal to 0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Progression {
+    /// The first (smallest) period of the progression
+    pub first: usize,
+    /// The difference between two consecutive periods of the progression
+    pub difference: usize,
+    /// The number of periods in the progression
+    pub count: usize,
+}
+
+impl Progression {
+    /// Returns the last (largest) period of the progression.
+    pub fn last(&self) -> usize {
+        self.first + (self.count - 1) * self.difference
+    }
+
+    /// Returns true if `p` belongs to the progression.
+    pub fn contains(&self, p: usize) -> bool {
+        if p < self.first || p > self.last() {
+            return false;
+        }
+        (p - self.first).is_multiple_of(self.difference)
+    }
+
+    /// Returns an iterator over the periods of the progression, in increasing order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> {
+        let Progression {
+            first, difference, ..
+        } = *self;
+        (0..self.count).map(move |k| first + k * difference)
+    }
+}
+
+impl Periods {
+    /// Appends a period greater than all the periods already in the set.
+    fn push(&mut self, p: usize) {
+        if let Some(last) = self.progressions.last_mut() {
+            if last.count == 1 {
+                last.difference = p - last.first;
+                last.count = 2;
+                return;
+            }
+            if p == last.last() + last.difference {
+                last.count += 1;
+                return;
+            }
+        }
+        self.progressions.push(Progression {
+            first: p,
+            difference: 0,
+            count: 1,
+        });
+    }
+
+    /// Returns the arithmetic progressions the periods are grouped in, in increasing order.
+    pub fn progressions(&self) -> &[Progression] {
+        &self.progressions
+    }
+
+    /// Returns the number of periods.
+    pub fn len(&self) -> usize {
+        self.progressions
+            .iter()
+            .map(|progression| progression.count)
+            .sum()
+    }
+
+    /// Returns true if the set has no periods, which only happens for the empty string.
+    pub fn is_empty(&self) -> bool {
+        self.progressions.is_empty()
+    }
+
+    /// Returns the minimum period, if any.
+    pub fn min(&self) -> Option<usize> {
+        self.progressions
+            .first()
+            .map(|progression| progression.first)
+    }
+
+    /// Returns true if `p` is a period of the string.
+    pub fn contains(&self, p: usize) -> bool {
+        self.progressions
+            .iter()
+            .any(|progression| progression.contains(p))
+    }
+
+    /// Returns an iterator over all the periods, in increasing order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.progressions.iter().flat_map(Progression::iter)
+    }
+}
+
+/// Returns true if the string is primitive, that is it is not a power `w^k` of another string with
+/// `k > 1`. The empty string is not primitive.
+///
+/// A non empty string is primitive if and only if its minimum period doesn't divide its length or
+/// is equal to it.
+///
+/// # Arguments
+///
+/// * `s` - The string to be analyzed
+///
+/// # Examples
+///
+/// ```
+/// use fractional_period::{algorithms::is_primitive, input::InputString};
+///
+/// let s: InputString = "abaaba".try_into().unwrap();
+/// assert!(!is_primitive(&s));
+/// let s: InputString = "abaab".try_into().unwrap();
+/// assert!(is_primitive(&s));
+/// ```
+pub fn is_primitive(s: &InputString) -> bool {
+    !s.is_empty() && primitive_root(s).1 == 1
+}
+
+/// Returns the primitive root of a string together with its exponent, that is the primitive
+/// string `w` and the integer `k` such that `s = w^k`.
+///
+/// If the minimum period `p` of the string divides its length the primitive root is `s[0..p]`
+/// with exponent `n / p`, otherwise the string is primitive and it is its own root. As its period,
+/// the exponent of the empty string is 0, with the empty string as root.
+///
+/// # Arguments
+///
+/// * `s` - The string to be analyzed
+///
+/// # Examples
+///
+/// ```
+/// use fractional_period::{algorithms::primitive_root, input::InputString};
+///
+/// let s: InputString = "abaabaaba".try_into().unwrap();
+/// assert_eq!(primitive_root(&s), (&b"aba"[..], 3));
+/// ```
+pub fn primitive_root(s: &InputString) -> (&[u8], usize) {
+    let n = s.len();
+    let period = period_smart(&s[..]);
+    if n == 0 {
+        (&s[..], 0)
+    } else if n.is_multiple_of(period) {
+        (&s[..period], n / period)
+    } else {
+        (&s[..], 1)
+    }
+}
+
+/// Returns the primitive root of a string together with its exponent, failing if the string is
+/// empty
+///
+/// # Arguments
+///
+/// * `s` - The string to be analyzed
+///
+/// # Errors
+///
+/// * [`Error::EmptyString`] if the string is empty
+pub fn try_primitive_root(s: &InputString) -> Result<(&[u8], usize), Error> {
+    if s.is_empty() {
+        return Err(Error::EmptyString);
+    }
+    Ok(primitive_root(s))
+}
+
+/// The ordering of the characters used to compare the suffixes of a string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SuffixOrder {
+    /// The usual lexicographic order
+    Lexicographic,
+    /// The lexicographic order induced by the reversed order of the characters
+    Reversed,
+}
+
+/// The maximal suffix of a string with respect to a [`SuffixOrder`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MaximalSuffix {
+    /// The starting position of the maximal suffix
+    pub position: usize,
+    /// The period of the maximal suffix
+    pub period: usize,
+}
+
+/// Computes the maximal suffix of a string and its period in linear time and constant extra
+/// space.
+///
+/// # Arguments
+///
+/// * `s` - The string to be analyzed
+/// * `order` - The ordering of the characters
+///
+/// # Examples
+///
+/// ```
+/// use fractional_period::{
+///     algorithms::{maximal_suffix, SuffixOrder},
+///     input::InputString,
+/// };
+///
+/// let s: InputString = "abcabcab".try_into().unwrap();
+/// assert_eq!(maximal_suffix(&s[..], SuffixOrder::Lexicographic).position, 2);
+/// assert_eq!(maximal_suffix(&s[..], SuffixOrder::Reversed).position, 0);
+/// ```
+pub fn maximal_suffix<T: Ord>(s: &[T], order: SuffixOrder) -> MaximalSuffix {
+    let reversed = order == SuffixOrder::Reversed;
+
+    // start of the current maximal suffix
+    let mut left = 0;
+    // start of the candidate suffix
+    let mut right = 1;
+    // number of characters of the candidate already compared
+    let mut offset = 0;
+    // period of the current maximal suffix
+    let mut period = 1;
+
+    while right + offset < s.len() {
+        let a = &s[right + offset];
+        let b = &s[left + offset];
+        if (a < b && !reversed) || (a > b && reversed) {
+            // the candidate is smaller, the period is the entire prefix so far
+            right += offset + 1;
+            offset = 0;
+            period = right - left;
+        } else if a == b {
+            // advance through the repetition of the current period
+            if offset + 1 == period {
+                right += offset + 1;
+                offset = 0;
+            } else {
+                offset += 1;
+            }
+        } else {
+            // the candidate is larger, start over from it
+            left = right;
+            right += 1;
+            offset = 0;
+            period = 1;
+        }
+    }
+    MaximalSuffix {
+        position: left,
+        period,
+    }
+}
+
+/// A critical factorization `s = uv` of a string, as computed by [`critical_factorization`].
+///
+/// By the critical factorization theorem the local period at a critical position (see
+/// [`local_period`]) is equal to the period of the entire string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CriticalFactorization {
+    /// The critical position, that is the length of `u`
+    pub position: usize,
+    /// The period of `v`, the suffix starting at the critical position
+    pub suffix_period: usize,
+}
+
+/// Computes a critical factorization of a string in linear time and constant extra space, as the
+/// longest of the maximal suffixes with respect to the two orderings (Crochemore–Perrin).
+///
+/// The critical position is always smaller than the period of the string, except for the empty
+/// string whose critical position and period are both 0.
+///
+/// # Arguments
+///
+/// * `s` - The string to be analyzed
+///
+/// # Examples
+///
+/// ```
+/// use fractional_period::{
+///     algorithms::{critical_factorization, local_period, period_smart},
+///     input::InputString,
+/// };
+///
+/// let s: InputString = "abaababaab".try_into().unwrap();
+/// let factorization = critical_factorization(&s[..]);
+/// assert_eq!(factorization.position, 4);
+/// assert_eq!(local_period(&s[..], factorization.position), period_smart(&s[..]));
+/// ```
+pub fn critical_factorization<T: Ord>(s: &[T]) -> CriticalFactorization {
+    let lexicographic = maximal_suffix(s, SuffixOrder::Lexicographic);
+    let reversed = maximal_suffix(s, SuffixOrder::Reversed);
+    let maximal_suffix = if lexicographic.position > reversed.position {
+        lexicographic
+    } else {
+        reversed
+    };
+    CriticalFactorization {
+        position: maximal_suffix.position,
+        suffix_period: maximal_suffix.period,
+    }
+}
+
+/// Computes the local period of a string at the given position, that is the length of the
+/// shortest square `ww` centered at `position` and compatible with the string (`w` may overflow
+/// the string on both sides). The local period of the empty string is 0.
+///
+/// Time complexity: O(n<sup>2</sup>)
+///
+/// # Arguments
+///
+/// * `s` - The string to be analyzed
+/// * `position` - The position between `s[position - 1]` and `s[position]`
+///
+/// # Panics
+///
+/// * Panics if `position` is greater than the length of the string
+pub fn local_period<T: Eq>(s: &[T], position: usize) -> usize {
+    let n = s.len();
+    assert!(
+        position <= n,
+        "The position must not exceed the length of the string"
+    );
+    // as the period, the local period of the empty string is 0
+    if n == 0 {
+        return 0;
+    }
+
+    (1..=n)
+        .find(|&r| {
+            let start = position.saturating_sub(r);
+            let end = position.min(n.saturating_sub(r));
+            (start..end).all(|i| s[i] == s[i + r])
+        })
+        .unwrap_or(1)
 }
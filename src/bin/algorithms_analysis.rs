@@ -1,4 +1,9 @@
-use fractional_period::algorithms::{PERIOD_NAIVE1, PERIOD_NAIVE2, PERIOD_SMART};
+mod common;
+
+use std::{env, fs};
+
+use common::experiment_seed;
+use fractional_period::algorithms::Registry;
 use fractional_period::input::{StringGen, StringGenFunction};
 
 use chrono_probe::plot::{PlotConfig, Scale};
@@ -9,11 +14,31 @@ use chrono_probe::{
 };
 
 fn main() {
+    // Select the algorithms to measure by name from the command line, all of them by default
+    let names = env::args().skip(1).collect::<Vec<_>>();
+    let registry = if names.is_empty() {
+        Registry::builtin()
+    } else {
+        Registry::builtin()
+            .select(&names)
+            .unwrap_or_else(|error| panic!("{error}"))
+    };
+
+    // The seed of the generation, for reproducibility
+    let seed = experiment_seed();
+
     // Create a distribution for the length of the strings
     let length_distribution = distribution::Reciprocal::new(1000..=500_000);
 
-    // Generation method for the strings
-    let string_gen = StringGen::new(StringGenFunction::CreateRandomString1, vec![b'a', b'b']);
+    // Generation method for the strings, selected by the GENERATOR environment variable (e.g.
+    // GENERATOR=CreateRandomString4 for the worst case of the naive and border algorithms)
+    let function = match env::var("GENERATOR") {
+        Ok(name) => name.parse().unwrap_or_else(|error| panic!("{error}")),
+        Err(_) => StringGenFunction::CreateRandomString1,
+    };
+    // A binary alphabet, unless the generation method needs more characters
+    let alphabet = (b'a'..).take(function.min_char_set_len().max(2)).collect();
+    let string_gen = StringGen::new(function, alphabet).with_seed(seed);
 
     // Create the builder for the strings
     let string_builder = InputBuilder::new(length_distribution, string_gen);
@@ -22,18 +47,15 @@ fn main() {
     let strings = string_builder.build(100);
 
     // Create a slice of the algorithms we want to measure
-    let algorithms = [
-        (PERIOD_NAIVE1.function, PERIOD_NAIVE1.name),
-        (PERIOD_NAIVE2.function, PERIOD_NAIVE2.name),
-        (PERIOD_SMART.function, PERIOD_SMART.name),
-    ];
+    let algorithms = registry.measurable();
 
     // Measure the algorithms on the strings
     let results = measure(&strings, &algorithms, 0.001);
 
-    // save data to json file
+    // save data to json file, and the seed of the strings next to it
     let result_clone = results.clone();
     result_clone.serialize_json("results.json");
+    fs::write("results_seed.txt", format!("{seed}\n")).unwrap_or_else(|error| panic!("{error}"));
 
     /*
     for result in result_clone.measurements {
@@ -46,7 +68,9 @@ fn main() {
     let config = PlotConfig::default()
         .with_scale(Scale::LogLog)
         .with_title("Fractional Period")
-        .with_caption("The time plot of fractional period algorithms");
+        .with_caption(&format!(
+            "The time plot of fractional period algorithms on {function:?} (seed {seed})"
+        ));
 
     time_plot("results/tick_control.svg", results, &config);
 }
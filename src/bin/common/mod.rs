@@ -0,0 +1,20 @@
+// Helpers shared by the analysis binaries
+
+/// Returns the seed of an experiment: the value of the `SEED` environment variable if set,
+/// otherwise a random one, which is printed so that the experiment can be reproduced.
+///
+/// # Panics
+///
+/// * Panics if the `SEED` environment variable is not a valid `u64`
+pub fn experiment_seed() -> u64 {
+    match std::env::var("SEED") {
+        Ok(seed) => seed
+            .parse()
+            .unwrap_or_else(|_| panic!("The seed {seed:?} is not a valid u64")),
+        Err(_) => {
+            let seed = rand::random();
+            println!("Seed: {seed} (set the SEED environment variable to reproduce the results)");
+            seed
+        }
+    }
+}
@@ -0,0 +1,54 @@
+mod common;
+
+use common::experiment_seed;
+use fractional_period::comparisons::{COMPARISONS_NAIVE1, COMPARISONS_NAIVE2, COMPARISONS_SMART};
+use fractional_period::input::{StringGen, StringGenFunction};
+
+use chrono_probe::input::distribution::Uniform;
+use chrono_probe::input::InputBuilder;
+use fractional_period::input_plot;
+
+fn main() {
+    // The seed of the generation, for reproducibility
+    let seed = experiment_seed();
+
+    // Generation methods for the strings, the Tribonacci word needs three characters
+    let alphabet = vec![b'a', b'b', b'c'];
+    let names = StringGenFunction::ALL
+        .iter()
+        .map(|function| format!("{function:?}"))
+        .collect::<Vec<_>>();
+
+    // The instrumented algorithms, with the constant of their linear bound on the number of
+    // comparisons if any
+    let algorithms = [
+        (COMPARISONS_NAIVE1, None, "results/comparisons_naive1.svg"),
+        (COMPARISONS_NAIVE2, None, "results/comparisons_naive2.svg"),
+        (COMPARISONS_SMART, Some(3), "results/comparisons_smart.svg"),
+    ];
+
+    // Plot a graph of the comparisons for each algorithm
+    for (algorithm, bound, path) in algorithms.iter() {
+        // Create a distribution for the length of the strings: the quadratic algorithms would count
+        // billions of comparisons on their worst cases of length 100000, so their strings are
+        // shorter
+        let max_length = if bound.is_some() { 100_000 } else { 2_000 };
+        let length_distribution = Uniform::new(max_length / 100..=max_length);
+
+        // Create the builders for the strings
+        let input_gen = StringGenFunction::ALL
+            .iter()
+            .zip(names.iter())
+            .map(|(&function, name)| {
+                (
+                    InputBuilder::new(
+                        length_distribution.clone(),
+                        StringGen::new(function, alphabet.clone()).with_seed(seed),
+                    ),
+                    name.as_str(),
+                )
+            })
+            .collect();
+        input_plot::comparisons_analysis(input_gen, algorithm, *bound, path, 20, 10, Some(seed));
+    }
+}
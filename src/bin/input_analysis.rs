@@ -1,5 +1,13 @@
-use fractional_period::algorithms::PERIOD_SMART;
+mod common;
+
+use std::env;
+
+use common::experiment_seed;
+#[cfg(not(feature = "parallel"))]
+use fractional_period::algorithms::PeriodAlgorithm;
+use fractional_period::algorithms::PeriodSolver;
 use fractional_period::input::{StringGen, StringGenFunction};
+use fractional_period::markov::MarkovModel;
 
 use chrono_probe::input::distribution::Uniform;
 use chrono_probe::input::InputBuilder;
@@ -7,31 +15,50 @@ use fractional_period::input_plot;
 
 fn main() {
     // Input Analysis
+    // The seed of the generation, for reproducibility
+    let seed = experiment_seed();
+
     // Create a distribution for the length of the strings
     let distribution_max = 500_000;
     let length_distribution = Uniform::new(500_000..=distribution_max);
 
     // Generation method for the strings
     let alphabet = vec![b'a', b'b'];
-    let string_gens = vec![
+    let mut string_gens = vec![
         (
-            StringGen::new(StringGenFunction::CreateRandomString1, alphabet.clone()),
+            StringGen::new(StringGenFunction::CreateRandomString1, alphabet.clone())
+                .with_seed(seed),
             "CreateRandomString1",
         ),
         (
-            StringGen::new(StringGenFunction::CreateRandomString2, alphabet.clone()),
+            StringGen::new(StringGenFunction::CreateRandomString2, alphabet.clone())
+                .with_seed(seed),
             "CreateRandomString2",
         ),
         (
-            StringGen::new(StringGenFunction::CreateRandomString3, alphabet.clone()),
+            StringGen::new(StringGenFunction::CreateRandomString3, alphabet.clone())
+                .with_seed(seed),
             "CreateRandomString3",
         ),
         (
-            StringGen::new(StringGenFunction::CreateRandomString4, alphabet.clone()),
+            StringGen::new(StringGenFunction::CreateRandomString4, alphabet.clone())
+                .with_seed(seed),
             "CreateRandomString4",
         ),
     ];
 
+    // A generator trained on a sample file (e.g. a text, a DNA sequence or a log file) if the
+    // SAMPLE environment variable is set, ORDER sets the order of the model (3 by default)
+    if let Ok(path) = env::var("SAMPLE") {
+        let order = env::var("ORDER").map_or(3, |order| {
+            order
+                .parse()
+                .unwrap_or_else(|_| panic!("The order {order:?} is not a valid number"))
+        });
+        let model = MarkovModel::from_file(&path, order).unwrap_or_else(|error| panic!("{error}"));
+        string_gens.push((StringGen::from_model(model).with_seed(seed), "MarkovModel"));
+    }
+
     // Create the builder for the strings
     let string_builders = string_gens
         .iter()
@@ -44,17 +71,22 @@ fn main() {
         .collect::<Vec<_>>();
 
     // Plot a graph of with the input generation analysis
-    input_plot::input_analysis(string_builders, distribution_max, 1, 1000);
+    input_plot::input_analysis(string_builders, distribution_max, 1, 1000, Some(seed));
 
     // TODO: da finire l'expected value
     let input_set = InputBuilder::new(
         length_distribution,
-        StringGen::new(StringGenFunction::CreateRandomString1, alphabet.clone()),
+        StringGen::new(StringGenFunction::CreateRandomString1, alphabet.clone()).with_seed(seed),
     )
     .build(1000);
 
-    let expected_value = PERIOD_SMART.expected_value(&input_set);
+    // Evaluate the strings in parallel if the `parallel` feature is enabled
+    #[cfg(feature = "parallel")]
+    let expected_value = PeriodSolver::par_expected_value(&input_set);
+    #[cfg(not(feature = "parallel"))]
+    let expected_value = PeriodSolver::with_capacity(distribution_max).expected_value(&input_set);
 
     println!("Expected value: {}", expected_value);
     println!("Expected edge value: {}", 500_000.0 - expected_value);
+    println!("Seed: {}", seed);
 }
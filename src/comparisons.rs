@@ -0,0 +1,81 @@
+use std::cell::Cell;
+
+use crate::algorithms::{period_naive1, period_naive2, period_smart, Algorithm};
+
+/// An element of a string which counts the comparisons it takes part in.
+///
+/// Since the period algorithms are generic over the element type, running them on a string of
+/// [`Counted`] elements gives the exact number of character comparisons they perform, see
+/// [`count_comparisons`].
+#[derive(Clone, Copy, Debug)]
+pub struct Counted<'a, T> {
+    /// The wrapped element
+    pub value: T,
+    comparisons: &'a Cell<usize>,
+}
+
+impl<T: PartialEq> PartialEq for Counted<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.comparisons.set(self.comparisons.get() + 1);
+        self.value == other.value
+    }
+}
+
+impl<T: Eq> Eq for Counted<'_, T> {}
+
+/// Runs the given algorithm on the string, returning its result together with the number of
+/// element comparisons it performed.
+///
+/// # Arguments
+///
+/// * `s` - The string to be analyzed
+/// * `algorithm` - The algorithm to be instrumented
+///
+/// # Examples
+///
+/// ```
+/// use fractional_period::{algorithms::period_naive1, comparisons::count_comparisons};
+///
+/// // The shift by 1 fails at the first comparison, the shift by 2 needs a single one
+/// assert_eq!(count_comparisons(&b"aba"[..], |s| period_naive1(s)), (2, 2));
+/// ```
+pub fn count_comparisons<T: Copy, R>(
+    s: &[T],
+    algorithm: impl FnOnce(&[Counted<T>]) -> R,
+) -> (R, usize) {
+    let comparisons = Cell::new(0);
+    let counted = s
+        .iter()
+        .map(|&value| Counted {
+            value,
+            comparisons: &comparisons,
+        })
+        .collect::<Vec<_>>();
+    let result = algorithm(&counted);
+    (result, comparisons.get())
+}
+
+// Instrumented versions of the period algorithms: they return the number of character comparisons
+// performed instead of the period, so that e.g. `Algorithm::expected_value` gives the mean number
+// of comparisons over an input set:
+
+/// Number of character comparisons performed by [`period_naive1`].
+/// Bound: O(n<sup>2</sup>)
+pub const COMPARISONS_NAIVE1: Algorithm = Algorithm {
+    name: "comparisons naive 1",
+    function: |s| count_comparisons(&s[..], |s| period_naive1(s)).1,
+};
+
+/// Number of character comparisons performed by [`period_naive2`].
+/// Bound: O(n<sup>2</sup>)
+pub const COMPARISONS_NAIVE2: Algorithm = Algorithm {
+    name: "comparisons naive 2",
+    function: |s| count_comparisons(&s[..], |s| period_naive2(s)).1,
+};
+
+/// Number of character comparisons performed by [`period_smart`].
+/// Bound: 3n - 4 for n ≥ 2, reached by a<sup>n-1</sup>b
+pub const COMPARISONS_SMART: Algorithm = Algorithm {
+    name: "comparisons smart",
+    function: |s| count_comparisons(&s[..], |s| period_smart(s)).1,
+};
@@ -0,0 +1,144 @@
+use std::fmt;
+
+/// The errors returned by the fallible functions of the crate.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The string contains a non ascii byte at the given position
+    NonAsciiByte {
+        /// The position of the byte in the string
+        position: usize,
+        /// The non ascii byte
+        byte: u8,
+    },
+    /// The character set is empty
+    EmptyAlphabet,
+    /// The character set contains the given symbol more than once
+    DuplicateSymbol(char),
+    /// The character set contains the given non ascii symbol
+    NonAsciiSymbol(char),
+    /// The character set is too small for the generation method
+    AlphabetTooSmall {
+        /// The minimum number of symbols required by the generation method
+        required: usize,
+        /// The number of symbols in the character set
+        actual: usize,
+    },
+    /// The character set is too large
+    AlphabetTooLarge {
+        /// The maximum number of symbols allowed
+        maximum: usize,
+        /// The number of symbols in the character set
+        actual: usize,
+    },
+    /// The length of the string to be generated is zero
+    ZeroLength,
+    /// The string is empty
+    EmptyString,
+    /// No algorithm with the given name is registered
+    UnknownAlgorithm(String),
+    /// An algorithm with the given name is registered or selected more than once
+    DuplicateAlgorithm(String),
+    /// No generation function has the given name
+    UnknownGenerator(String),
+    /// The frequencies of the characters are negative, not finite or all zero
+    InvalidFrequencies,
+    /// The sample is too short to train a model of the requested order
+    SampleTooShort {
+        /// The minimum length of the sample
+        required: usize,
+        /// The length of the sample
+        actual: usize,
+    },
+    /// The length of a context of a Markov model is not the order of the model
+    ContextLength {
+        /// The order of the model
+        order: usize,
+        /// The length of the context
+        actual: usize,
+    },
+    /// The transitions of the given context of a Markov model are given more than once
+    DuplicateContext(String),
+    /// The given context of a Markov model can be reached during the generation but has no
+    /// transitions
+    MissingContext(String),
+    /// An I/O error, with its message
+    Io(String),
+    /// The target period is zero, an empty range or a fraction not in (0, 1]
+    InvalidPeriod,
+    /// The target period is longer than the string to be generated
+    PeriodTooLong {
+        /// The target period
+        period: usize,
+        /// The length of the string to be generated
+        length: usize,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::NonAsciiByte { position, byte } => write!(
+                f,
+                "The string contains the non ascii byte {byte:#04x} at position {position}"
+            ),
+            Error::EmptyAlphabet => write!(f, "The character set must not be empty"),
+            Error::DuplicateSymbol(symbol) => write!(
+                f,
+                "The character set contains repetitions of the symbol {symbol:?}"
+            ),
+            Error::NonAsciiSymbol(symbol) => write!(
+                f,
+                "The character set contains the non ascii symbol {symbol:?}"
+            ),
+            Error::AlphabetTooSmall { required, actual } => write!(
+                f,
+                "The character set contains {actual} symbols, but the generation method requires at least {required}"
+            ),
+            Error::AlphabetTooLarge { maximum, actual } => write!(
+                f,
+                "The character set contains {actual} symbols, but at most {maximum} are allowed"
+            ),
+            Error::ZeroLength => write!(
+                f,
+                "The length of the string to be generated must be greater than 0"
+            ),
+            Error::EmptyString => write!(f, "The string must not be empty"),
+            Error::UnknownAlgorithm(name) => write!(f, "Unknown algorithm {name:?}"),
+            Error::DuplicateAlgorithm(name) => {
+                write!(f, "The algorithm {name:?} is given more than once")
+            }
+            Error::UnknownGenerator(name) => write!(f, "Unknown generation function {name:?}"),
+            Error::InvalidFrequencies => write!(
+                f,
+                "The frequencies must be finite, non negative and not all zero"
+            ),
+            Error::SampleTooShort { required, actual } => write!(
+                f,
+                "The sample contains {actual} characters, but the model requires at least {required}"
+            ),
+            Error::ContextLength { order, actual } => write!(
+                f,
+                "The context contains {actual} characters, but the order of the model is {order}"
+            ),
+            Error::DuplicateContext(context) => write!(
+                f,
+                "The transitions of the context {context:?} are given more than once"
+            ),
+            Error::MissingContext(context) => write!(
+                f,
+                "The context {context:?} can be reached during the generation but has no transitions"
+            ),
+            Error::Io(message) => write!(f, "I/O error: {message}"),
+            Error::InvalidPeriod => write!(
+                f,
+                "The target period must be positive, a non empty range or a fraction in (0, 1]"
+            ),
+            Error::PeriodTooLong { period, length } => write!(
+                f,
+                "The target period {period} is longer than the string of length {length}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
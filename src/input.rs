@@ -1,7 +1,12 @@
-use rand::{thread_rng, Rng};
-use std::ops::Deref;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::collections::HashMap;
+use std::ops::{Deref, RangeInclusive};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
 use chrono_probe::input::Input;
 
+use crate::{algorithms::PeriodSolver, error::Error, markov::MarkovModel};
+
 /// Struct that represent an input string.
 ///
 /// The input string is represented as a vector of bytes because of how Rust handles strings.
@@ -13,17 +18,18 @@ use chrono_probe::input::Input;
 #[derive(Clone)]
 pub struct InputString(pub Vec<u8>);
 
-/// Convert a string to an `InputString`.
+/// Convert a string to an `InputString`, failing if the string contains non ascii characters.
 impl TryFrom<&str> for InputString {
-    type Error = ();
+    type Error = Error;
 
     fn try_from(s: &str) -> Result<Self, Self::Error> {
-        if s.chars().all(|c| c.is_ascii()) {
-            Ok(InputString(s.as_bytes().to_vec()))
-        } else {
-            Err(())
+        match s.bytes().position(|byte| !byte.is_ascii()) {
+            Some(position) => Err(Error::NonAsciiByte {
+                position,
+                byte: s.as_bytes()[position],
+            }),
+            None => Ok(InputString(s.as_bytes().to_vec())),
         }
-
     }
 }
 
@@ -48,26 +54,70 @@ impl Input for InputString {
     }
 }
 
-fn create_random_string1(n: usize, char_set: &Vec<u8>) -> Vec<u8> {
+/// Struct that represents an input string made of Unicode scalar values.
+///
+/// Unlike [`InputString`] every character of the string is a `char`, so any text can be analyzed
+/// (e.g. the period of "αβαβα" is 2), at the cost of using four bytes for each character.
+///
+/// # Examples
+///
+/// ```
+/// use fractional_period::{algorithms::period_smart, input::UnicodeString};
+///
+/// let s = UnicodeString::from("αβαβα");
+/// assert_eq!(period_smart(&s[..]), 2);
+/// ```
+#[derive(Clone)]
+pub struct UnicodeString(pub Vec<char>);
+
+/// Convert a string to a `UnicodeString`.
+impl From<&str> for UnicodeString {
+    fn from(s: &str) -> Self {
+        UnicodeString(s.chars().collect())
+    }
+}
+
+impl Deref for UnicodeString {
+    type Target = Vec<char>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Implementation of the `Input` trait of time-complexity-plot for `UnicodeString`.
+impl Input for UnicodeString {
+    type Builder = UnicodeStringGen;
+
+    fn get_size(&self) -> usize {
+        self.len()
+    }
+
+    fn generate_input(size: usize, builder: &Self::Builder) -> Self {
+        UnicodeString(builder.create_random_string(size))
+    }
+}
+
+fn create_random_string1(n: usize, char_set: &[u8], rng: &mut StdRng) -> Vec<u8> {
     let mut s: Vec<u8> = Vec::with_capacity(n);
     let number_of_chars = char_set.len();
     for _ in 0..n {
         // generate random character
-        let char_index = thread_rng().gen_range(0..number_of_chars);
+        let char_index = rng.gen_range(0..number_of_chars);
         let char = char_set[char_index];
         s.push(char);
     }
     s
 }
 
-fn create_random_string2(n: usize, char_set: &Vec<u8>) -> Vec<u8> {
+fn create_random_string2(n: usize, char_set: &[u8], rng: &mut StdRng) -> Vec<u8> {
     let mut s: Vec<u8> = Vec::with_capacity(n);
     let number_of_chars = char_set.len();
     // TODO: This value should not be generated each time
-    let q = thread_rng().gen_range(1..=n);
+    let q = rng.gen_range(1..=n);
     for _ in 0..q {
         // generate random character
-        let char_index = thread_rng().gen_range(0..number_of_chars);
+        let char_index = rng.gen_range(0..number_of_chars);
         let char = char_set[char_index];
         s.push(char);
     }
@@ -78,7 +128,7 @@ fn create_random_string2(n: usize, char_set: &Vec<u8>) -> Vec<u8> {
     s
 }
 
-fn create_random_string3(n: usize, char_set: &Vec<u8>) -> Vec<u8> {
+fn create_random_string3(n: usize, char_set: &[u8], rng: &mut StdRng) -> Vec<u8> {
     // new ascii character
     fn new_char(char_set: &[u8]) -> u8 {
         for i in 0..128 {
@@ -91,10 +141,10 @@ fn create_random_string3(n: usize, char_set: &Vec<u8>) -> Vec<u8> {
 
     let mut s: Vec<u8> = Vec::with_capacity(n);
     let number_of_chars = char_set.len();
-    let q = thread_rng().gen_range(1..=n);
+    let q = rng.gen_range(1..=n);
     for _ in 0..q {
         // generate random character
-        let char_index = thread_rng().gen_range(0..number_of_chars);
+        let char_index = rng.gen_range(0..number_of_chars);
         let char = char_set[char_index];
         s.push(char);
     }
@@ -106,37 +156,322 @@ fn create_random_string3(n: usize, char_set: &Vec<u8>) -> Vec<u8> {
     s
 }
 
-fn create_random_string4(n: usize, char_set: &Vec<u8>) -> Vec<u8> {
+fn create_random_string4(n: usize, char_set: &[u8], _rng: &mut StdRng) -> Vec<u8> {
     let mut s = vec![char_set[0]; n];
     s[n-1] = char_set[1];
     s
 }
 
+fn create_worst_case_naive_simd_string(n: usize, char_set: &[u8], _rng: &mut StdRng) -> Vec<u8> {
+    // a^(n-2) b a: every shift but the shifts by 1 and n - 2 preserves the first and the last
+    // character, and fails only at the b after scanning all the characters before it
+    let mut s = vec![char_set[0]; n];
+    if n >= 2 {
+        s[n - 2] = char_set[1];
+    }
+    s
+}
+
+/// Creates a random string of length `n` whose minimal period is exactly `p`, drawn uniformly
+/// among all such strings.
+///
+/// A random prefix of length `p` is repeated up to length `n`, and rejected if the result has a
+/// shorter period. Only the first `min(n, 2p)` characters are checked: if they have a period
+/// `q < p`, by the Fine-Wilf theorem they also have the period `gcd(p, q)`, which divides `p` and
+/// is therefore a period of the whole string. Every unbordered prefix is accepted, so with at
+/// least two characters a constant fraction of the attempts succeeds.
+///
+/// # Arguments
+///
+/// * `n` - The length of the string, at least `p`
+/// * `p` - The period of the string, at least 1
+/// * `char_set` - The character set, with at least two characters if `p > 1`
+/// * `rng` - The random number generator
+fn create_string_with_period(n: usize, p: usize, char_set: &[u8], rng: &mut StdRng) -> Vec<u8> {
+    let mut solver = PeriodSolver::with_capacity(n.min(2 * p));
+    let mut s: Vec<u8> = Vec::with_capacity(n);
+    loop {
+        s.clear();
+        for _ in 0..p {
+            s.push(char_set[rng.gen_range(0..char_set.len())]);
+        }
+        for i in p..n.min(2 * p) {
+            s.push(s[i - p]);
+        }
+        if solver.period(&s) == p {
+            break;
+        }
+    }
+    for i in s.len()..n {
+        s.push(s[i - p]);
+    }
+    s
+}
+
+/// Returns the prefix of length `n` of the fixed point of a morphism, mapping the letter `i` of the
+/// morphism to the character `char_set[i]`.
+///
+/// # Arguments
+///
+/// * `n` - The length of the prefix
+/// * `morphism` - The images of the letters, the image of the letter 0 must start with 0 and have
+///   at least two letters
+/// * `char_set` - The characters corresponding to the letters
+fn morphic_word(n: usize, morphism: &[&[u8]], char_set: &[u8]) -> Vec<u8> {
+    // the word is always the image of its first i letters, so appending the image of the next
+    // letter gives a longer prefix of the fixed point
+    let mut word = morphism[0].to_vec();
+    let mut i = 1;
+    while word.len() < n {
+        word.extend_from_slice(morphism[word[i] as usize]);
+        i += 1;
+    }
+    word.truncate(n);
+    word.iter()
+        .map(|&letter| char_set[letter as usize])
+        .collect()
+}
+
+fn create_fibonacci_string(n: usize, char_set: &[u8], _rng: &mut StdRng) -> Vec<u8> {
+    // a -> ab, b -> a
+    morphic_word(n, &[&[0, 1], &[0]], char_set)
+}
+
+fn create_sturmian_string(n: usize, char_set: &[u8], rng: &mut StdRng) -> Vec<u8> {
+    // mechanical word of random slope and intercept: the letter at i is 1 if and only if the line
+    // y = slope * x + intercept crosses an integer between x = i + 1 and x = i + 2
+    let slope: f64 = rng.gen_range(0.0..1.0);
+    let intercept: f64 = rng.gen_range(0.0..1.0);
+    (0..n)
+        .map(|i| {
+            let crossings = ((i + 2) as f64 * slope + intercept).floor()
+                - ((i + 1) as f64 * slope + intercept).floor();
+            char_set[crossings as usize]
+        })
+        .collect()
+}
+
+fn create_thue_morse_string(n: usize, char_set: &[u8], _rng: &mut StdRng) -> Vec<u8> {
+    // the letter at i is the parity of the number of ones in the binary representation of i
+    (0..n)
+        .map(|i| char_set[(i.count_ones() % 2) as usize])
+        .collect()
+}
+
+fn create_period_doubling_string(n: usize, char_set: &[u8], _rng: &mut StdRng) -> Vec<u8> {
+    // a -> ab, b -> aa
+    morphic_word(n, &[&[0, 1], &[0, 0]], char_set)
+}
+
+fn create_tribonacci_string(n: usize, char_set: &[u8], _rng: &mut StdRng) -> Vec<u8> {
+    // a -> ab, b -> ac, c -> a
+    morphic_word(n, &[&[0, 1], &[0, 2], &[0]], char_set)
+}
+
 /// Enum that represents the different functions that can be used to generate a random string.
+///
+/// Among the algorithms of [`Registry::builtin`](crate::algorithms::Registry::builtin),
+/// `CreateRandomString4` is the worst case of the naive, border, prefix and Z-array algorithms and
+/// of the [`PeriodSolver`], and `WorstCaseNaiveSimd` the one of the SIMD naive algorithm.
+/// [`PERIOD_CONSTANT_SPACE`](crate::algorithms::PERIOD_CONSTANT_SPACE) has no tuned worst case:
+/// it only works on bytes, so [`count_comparisons`](crate::comparisons::count_comparisons) cannot
+/// count its comparisons.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum StringGenFunction {
     CreateRandomString1,
     CreateRandomString2,
     CreateRandomString3,
+    /// The string a<sup>n-1</sup>b: every shift fails only at the last character, so the naive
+    /// algorithms perform n(n - 1)/2 comparisons, the most possible, and the last character
+    /// backtracks through the whole border chain, so
+    /// [`period_smart`](crate::algorithms::period_smart) reaches its bound of 3n - 4 comparisons.
+    /// No binary string of length up to 12 makes the naive, border and Z-array algorithms perform
+    /// more comparisons
     CreateRandomString4,
+    /// The Fibonacci word, fixed point of the morphism a -> ab, b -> a
+    Fibonacci,
+    /// A Sturmian word of random slope, generalizing the Fibonacci word
+    Sturmian,
+    /// The Thue–Morse word, fixed point of the morphism a -> ab, b -> ba
+    ThueMorse,
+    /// The period-doubling word, fixed point of the morphism a -> ab, b -> aa
+    PeriodDoubling,
+    /// The Tribonacci word, fixed point of the morphism a -> ab, b -> ac, c -> a
+    Tribonacci,
+    /// Worst case of [`period_naive_simd`](crate::simd::period_naive_simd), a<sup>n-2</sup>ba:
+    /// its first and last character checks, which discard every shift of a<sup>n-1</sup>b,
+    /// discard only two of the shifts for n ≥ 4, so it compares n(n - 1)/2 characters, the most
+    /// among the binary strings of length up to 12
+    WorstCaseNaiveSimd,
 }
 
 impl StringGenFunction {
+    /// All the available generation functions.
+    pub const ALL: [StringGenFunction; 10] = [
+        StringGenFunction::CreateRandomString1,
+        StringGenFunction::CreateRandomString2,
+        StringGenFunction::CreateRandomString3,
+        StringGenFunction::CreateRandomString4,
+        StringGenFunction::Fibonacci,
+        StringGenFunction::Sturmian,
+        StringGenFunction::ThueMorse,
+        StringGenFunction::PeriodDoubling,
+        StringGenFunction::Tribonacci,
+        StringGenFunction::WorstCaseNaiveSimd,
+    ];
+
     /// Returns the function associated with the enum value.
-    fn get_function(&self) -> fn(n: usize, char_set: &Vec<u8>) -> Vec<u8> {
+    fn get_function(&self) -> GenFunction {
         match self {
             StringGenFunction::CreateRandomString1 => create_random_string1,
             StringGenFunction::CreateRandomString2 => create_random_string2,
             StringGenFunction::CreateRandomString3 => create_random_string3,
             StringGenFunction::CreateRandomString4 => create_random_string4,
+            StringGenFunction::Fibonacci => create_fibonacci_string,
+            StringGenFunction::Sturmian => create_sturmian_string,
+            StringGenFunction::ThueMorse => create_thue_morse_string,
+            StringGenFunction::PeriodDoubling => create_period_doubling_string,
+            StringGenFunction::Tribonacci => create_tribonacci_string,
+            StringGenFunction::WorstCaseNaiveSimd => create_worst_case_naive_simd_string,
+        }
+    }
+
+    /// Returns the minimum number of characters of the character set required by the function.
+    pub fn min_char_set_len(&self) -> usize {
+        match self {
+            StringGenFunction::CreateRandomString4
+            | StringGenFunction::Fibonacci
+            | StringGenFunction::Sturmian
+            | StringGenFunction::ThueMorse
+            | StringGenFunction::PeriodDoubling
+            | StringGenFunction::WorstCaseNaiveSimd => 2,
+            StringGenFunction::Tribonacci => 3,
+            _ => 1,
+        }
+    }
+
+    /// Returns the maximum number of characters of the character set allowed by the function.
+    fn max_char_set_len(&self) -> usize {
+        match self {
+            // a new ascii character, not contained in the character set, is needed
+            StringGenFunction::CreateRandomString3 => 127,
+            _ => 128,
+        }
+    }
+}
+
+/// Parses the name of a generation function, e.g. `"WorstCaseNaiveSimd"`.
+impl FromStr for StringGenFunction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        StringGenFunction::ALL
+            .into_iter()
+            .find(|function| format!("{function:?}") == s)
+            .ok_or_else(|| Error::UnknownGenerator(s.to_string()))
+    }
+}
+
+/// The period of the strings generated by [`StringGen::from_period`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum TargetPeriod {
+    /// The same period for every string
+    Fixed(usize),
+    /// A period drawn uniformly from the range, whose upper end is reduced to the length of the
+    /// string if longer
+    Uniform(RangeInclusive<usize>),
+    /// A fraction of the length of the string, rounded up
+    Fraction(f64),
+}
+
+impl TargetPeriod {
+    /// Checks that the target period is positive.
+    fn validate(&self) -> Result<(), Error> {
+        let valid = match self {
+            TargetPeriod::Fixed(p) => *p > 0,
+            TargetPeriod::Uniform(range) => *range.start() > 0 && !range.is_empty(),
+            TargetPeriod::Fraction(f) => *f > 0.0 && *f <= 1.0,
+        };
+        if valid {
+            Ok(())
+        } else {
+            Err(Error::InvalidPeriod)
+        }
+    }
+
+    /// Returns the period of a string of length `n`.
+    fn sample(&self, n: usize, rng: &mut StdRng) -> Result<usize, Error> {
+        let p = match self {
+            TargetPeriod::Fixed(p) => *p,
+            TargetPeriod::Uniform(range) if *range.start() > n => *range.start(),
+            TargetPeriod::Uniform(range) => rng.gen_range(*range.start()..=n.min(*range.end())),
+            TargetPeriod::Fraction(f) => ((n as f64 * f).ceil() as usize).clamp(1, n),
+        };
+        if p > n {
+            return Err(Error::PeriodTooLong {
+                period: p,
+                length: n,
+            });
         }
+        Ok(p)
     }
 }
 
+/// A function generating a random string of length `n` on the character set with the given random
+/// number generator.
+pub type GenFunction = fn(n: usize, char_set: &[u8], rng: &mut StdRng) -> Vec<u8>;
+
+/// The way a [`StringGen`] generates its strings.
+#[derive(Clone, Debug)]
+pub enum GenSource {
+    /// A generation function applied to the character set, see [`StringGen::new`]
+    Function(GenFunction),
+    /// A Markov model, see [`StringGen::from_model`]
+    Model(Arc<MarkovModel>),
+    /// Random strings on the character set with the target period, see
+    /// [`StringGen::from_period`]
+    Period(TargetPeriod),
+}
+
 /// Struct that represents a string generator.
-#[derive(Clone)]
+///
+/// If a seed is set the generation is reproducible: the seed of every string is derived from the
+/// seed of the generator, the length of the string and the number of strings of the same length
+/// generated before it, so the `k`-th string of length `n` is always the same.
+///
+/// # Examples
+///
+/// ```
+/// use fractional_period::input::{StringGenFunction::CreateRandomString1, StringGen};
+///
+/// let string_gen = StringGen::new(CreateRandomString1, vec![b'a', b'b']).with_seed(42);
+/// let same_string_gen = StringGen::new(CreateRandomString1, vec![b'a', b'b']).with_seed(42);
+/// assert_eq!(
+///     string_gen.create_random_string(100),
+///     same_string_gen.create_random_string(100)
+/// );
+/// ```
 pub struct StringGen {
-    pub function: fn(n: usize, char_set: &Vec<u8>) -> Vec<u8>,
+    /// The way the strings are generated
+    pub source: GenSource,
     pub char_set: Vec<u8>,
+    /// The seed of the generation, `None` if the generation is not reproducible
+    pub seed: Option<u64>,
+    // the number of strings generated so far for each length
+    generated: Mutex<HashMap<usize, u64>>,
+}
+
+/// Cloning a generator also clones the number of strings it has generated, so that the clone
+/// generates the same strings as the original one would.
+impl Clone for StringGen {
+    fn clone(&self) -> Self {
+        Self {
+            source: self.source.clone(),
+            char_set: self.char_set.clone(),
+            seed: self.seed,
+            generated: Mutex::new(self.generated.lock().unwrap().clone()),
+        }
+    }
 }
 
 impl StringGen {
@@ -152,6 +487,8 @@ impl StringGen {
     /// * Panics if the character set is empty
     /// * Panics if the character set contains repetitions
     /// * Panics if the character set contains non ascii characters
+    /// * Panics if the character set is too small or too large for the function, see
+    ///   [`StringGen::try_new`]
     ///
     /// # Examples
     ///
@@ -162,20 +499,179 @@ impl StringGen {
     /// let string_gen = StringGen::new(CreateRandomString1, char_set);
     /// ```
     pub fn new(function: StringGenFunction, char_set: Vec<u8>) -> Self {
-        assert!(!char_set.is_empty(), "The character set must not be empty");
+        Self::try_new(function, char_set).unwrap_or_else(|error| panic!("{error}"))
+    }
 
-        // checking for repetitions in char_set
-        let mut char_set_sorted = char_set.clone();
-        char_set_sorted.sort_by(|a, b| b.cmp(a));
-        for i in 0..char_set_sorted.len() - 1 {
-            if char_set_sorted[i] == char_set_sorted[i + 1] {
-                panic!("The character set contains repetitions");
-            }
+    /// Creates a new StringGen struct, failing if the character set is not valid for the given
+    /// function
+    ///
+    /// # Arguments
+    ///
+    /// * `function` - The function used to generate the random string
+    /// * `char_set` - The character set used to generate the random string
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::EmptyAlphabet`] if the character set is empty
+    /// * [`Error::DuplicateSymbol`] if the character set contains repetitions
+    /// * [`Error::NonAsciiSymbol`] if the character set contains non ascii characters
+    /// * [`Error::AlphabetTooSmall`] if the character set has less characters than the ones
+    ///   required by the function (two for `CreateRandomString4`, the binary words and
+    ///   `WorstCaseNaiveSimd`, three for `Tribonacci`)
+    /// * [`Error::AlphabetTooLarge`] if the character set has more characters than the ones
+    ///   allowed by the function (`CreateRandomString3` needs an ascii character not contained in
+    ///   the character set)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractional_period::error::Error;
+    /// use fractional_period::input::{StringGenFunction::CreateRandomString4, StringGen};
+    ///
+    /// let string_gen = StringGen::try_new(CreateRandomString4, vec![b'a']);
+    /// assert_eq!(string_gen.err(), Some(Error::AlphabetTooSmall { required: 2, actual: 1 }));
+    /// ```
+    pub fn try_new(function: StringGenFunction, char_set: Vec<u8>) -> Result<Self, Error> {
+        check_char_set(&char_set)?;
+        if char_set.len() < function.min_char_set_len() {
+            return Err(Error::AlphabetTooSmall {
+                required: function.min_char_set_len(),
+                actual: char_set.len(),
+            });
         }
+        if char_set.len() > function.max_char_set_len() {
+            return Err(Error::AlphabetTooLarge {
+                maximum: function.max_char_set_len(),
+                actual: char_set.len(),
+            });
+        }
+
+        Ok(Self::from_source(
+            GenSource::Function(function.get_function()),
+            char_set,
+        ))
+    }
 
+    /// Creates a new StringGen struct generating the strings with a Markov model, e.g. trained on
+    /// a sample text so that the strings resemble realistic inputs
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The model generating the strings
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractional_period::{input::StringGen, markov::MarkovModel};
+    ///
+    /// let model = MarkovModel::train(b"GATTACAGATTACCA", 2).unwrap();
+    /// let string_gen = StringGen::from_model(model).with_seed(42);
+    /// assert_eq!(string_gen.char_set, b"ACGT");
+    /// ```
+    pub fn from_model(model: MarkovModel) -> Self {
+        let char_set = model.symbols();
+        Self::from_source(GenSource::Model(Arc::new(model)), char_set)
+    }
+
+    /// Creates a new StringGen struct generating random strings whose minimal period is exactly
+    /// the target one, unlike `CreateRandomString2` and `CreateRandomString3` whose strings can
+    /// have a shorter period than the chosen one
+    ///
+    /// # Arguments
+    ///
+    /// * `char_set` - The character set used to generate the random string
+    /// * `period` - The period of the generated strings
+    ///
+    /// # Panics
+    ///
+    /// * Panics if the character set or the period is not valid, see
+    ///   [`StringGen::try_from_period`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractional_period::algorithms::period_smart;
+    /// use fractional_period::input::{StringGen, TargetPeriod};
+    ///
+    /// let string_gen = StringGen::from_period(vec![b'a', b'b'], TargetPeriod::Fixed(7));
+    /// assert_eq!(period_smart(&string_gen.create_random_string(100)), 7);
+    /// ```
+    pub fn from_period(char_set: Vec<u8>, period: TargetPeriod) -> Self {
+        Self::try_from_period(char_set, period).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Creates a new StringGen struct generating random strings whose minimal period is exactly
+    /// the target one, failing if the character set or the period is not valid
+    ///
+    /// # Arguments
+    ///
+    /// * `char_set` - The character set used to generate the random string
+    /// * `period` - The period of the generated strings
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::EmptyAlphabet`] if the character set is empty
+    /// * [`Error::DuplicateSymbol`] if the character set contains repetitions
+    /// * [`Error::NonAsciiSymbol`] if the character set contains non ascii characters
+    /// * [`Error::AlphabetTooSmall`] if the character set has less than two characters
+    /// * [`Error::InvalidPeriod`] if the period is zero, an empty range or a fraction not in
+    ///   (0, 1]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractional_period::error::Error;
+    /// use fractional_period::input::{StringGen, TargetPeriod};
+    ///
+    /// let string_gen = StringGen::try_from_period(vec![b'a', b'b'], TargetPeriod::Fixed(0));
+    /// assert_eq!(string_gen.err(), Some(Error::InvalidPeriod));
+    /// ```
+    pub fn try_from_period(char_set: Vec<u8>, period: TargetPeriod) -> Result<Self, Error> {
+        check_char_set(&char_set)?;
+        if char_set.len() < 2 {
+            return Err(Error::AlphabetTooSmall {
+                required: 2,
+                actual: char_set.len(),
+            });
+        }
+        period.validate()?;
+        Ok(Self::from_source(GenSource::Period(period), char_set))
+    }
+
+    /// Creates a generator without a seed from a valid source and character set.
+    fn from_source(source: GenSource, char_set: Vec<u8>) -> Self {
         Self {
-            function: function.get_function(),
+            source,
             char_set,
+            seed: None,
+            generated: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Makes the generation reproducible with the given seed
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The seed from which the seeds of the strings are derived
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self.generated = Mutex::new(HashMap::new());
+        self
+    }
+
+    /// Returns the random number generator for the next string of length `n`: seeded with the
+    /// seed derived for the string if the generator has a seed, from the entropy of the system
+    /// otherwise.
+    fn rng(&self, n: usize) -> StdRng {
+        match self.seed {
+            Some(seed) => {
+                let mut generated = self.generated.lock().unwrap();
+                let index = generated.entry(n).or_insert(0);
+                let rng = StdRng::seed_from_u64(derive_seed(seed, n, *index));
+                *index += 1;
+                rng
+            }
+            None => StdRng::from_entropy(),
         }
     }
 
@@ -188,11 +684,196 @@ impl StringGen {
     /// # Panics
     ///
     /// * Panics if the length of the string to be generated is less than 1
+    /// * Panics if the target period of the generator is longer than the string
     pub fn create_random_string(&self, n: usize) -> Vec<u8> {
-        assert!(
-            n > 0,
-            "The length of the string to be generated must be greater than 0"
-        );
-        (self.function)(n, &self.char_set)
+        self.try_create_random_string(n)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Creates a random string using the character set specified in the struct, failing if the
+    /// length is not valid
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The length of the string to be generated
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::ZeroLength`] if the length of the string to be generated is less than 1
+    /// * [`Error::PeriodTooLong`] if the target period of the generator is longer than the string
+    pub fn try_create_random_string(&self, n: usize) -> Result<Vec<u8>, Error> {
+        if n == 0 {
+            return Err(Error::ZeroLength);
+        }
+        let mut rng = self.rng(n);
+        Ok(match &self.source {
+            GenSource::Function(function) => function(n, &self.char_set, &mut rng),
+            GenSource::Model(model) => model.generate(n, &mut rng),
+            GenSource::Period(period) => {
+                let p = period.sample(n, &mut rng)?;
+                create_string_with_period(n, p, &self.char_set, &mut rng)
+            }
+        })
+    }
+}
+
+/// Checks that the character set is not empty and contains distinct ascii characters.
+fn check_char_set(char_set: &[u8]) -> Result<(), Error> {
+    if char_set.is_empty() {
+        return Err(Error::EmptyAlphabet);
+    }
+    if let Some(&symbol) = char_set.iter().find(|symbol| !symbol.is_ascii()) {
+        return Err(Error::NonAsciiSymbol(char::from(symbol)));
+    }
+
+    // checking for repetitions in char_set
+    let mut char_set_sorted = char_set.to_vec();
+    char_set_sorted.sort_by(|a, b| b.cmp(a));
+    for i in 0..char_set_sorted.len() - 1 {
+        if char_set_sorted[i] == char_set_sorted[i + 1] {
+            return Err(Error::DuplicateSymbol(char::from(char_set_sorted[i])));
+        }
+    }
+    Ok(())
+}
+
+/// Derives the seed of the `index`-th string of length `n` from the seed of a generator, mixing
+/// the values with the SplitMix64 finalizer so that close inputs give unrelated seeds.
+fn derive_seed(seed: u64, n: usize, index: u64) -> u64 {
+    fn mix(mut x: u64) -> u64 {
+        x = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        x ^ (x >> 31)
+    }
+    mix(mix(mix(seed) ^ n as u64) ^ index)
+}
+
+/// Struct that represents a generator of strings made of Unicode scalar values.
+///
+/// The strings are generated by a [`StringGen`] on the indices of the characters in the character
+/// set, which are then replaced by the characters themselves.
+#[derive(Clone)]
+pub struct UnicodeStringGen {
+    string_gen: StringGen,
+    // the characters of the character set followed by a character not contained in it
+    symbols: Vec<char>,
+}
+
+impl UnicodeStringGen {
+    /// Creates a new UnicodeStringGen struct
+    ///
+    /// # Arguments
+    ///
+    /// * `function` - The function used to generate the random string
+    /// * `char_set` - The character set used to generate the random string
+    ///
+    /// # Panics
+    ///
+    /// * Panics if the character set is empty
+    /// * Panics if the character set contains repetitions
+    /// * Panics if the character set contains more than 127 characters
+    /// * Panics if the character set is too small for the function, see [`StringGen::try_new`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractional_period::input::{StringGenFunction::CreateRandomString1, UnicodeStringGen};
+    ///
+    /// let char_set = vec!['α', 'β', 'γ'];
+    /// let string_gen = UnicodeStringGen::new(CreateRandomString1, char_set);
+    /// ```
+    pub fn new(function: StringGenFunction, char_set: Vec<char>) -> Self {
+        Self::try_new(function, char_set).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Creates a new UnicodeStringGen struct, failing if the character set is not valid for the
+    /// given function
+    ///
+    /// # Arguments
+    ///
+    /// * `function` - The function used to generate the random string
+    /// * `char_set` - The character set used to generate the random string
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::AlphabetTooLarge`] if the character set contains more than 127 characters
+    /// * [`Error::DuplicateSymbol`] if the character set contains repetitions
+    /// * The errors of [`StringGen::try_new`] for the indices of the characters
+    pub fn try_new(function: StringGenFunction, char_set: Vec<char>) -> Result<Self, Error> {
+        if char_set.len() > 127 {
+            return Err(Error::AlphabetTooLarge {
+                maximum: 127,
+                actual: char_set.len(),
+            });
+        }
+
+        // checking for repetitions in char_set
+        let mut char_set_sorted = char_set.clone();
+        char_set_sorted.sort();
+        for i in 1..char_set_sorted.len() {
+            if char_set_sorted[i] == char_set_sorted[i - 1] {
+                return Err(Error::DuplicateSymbol(char_set_sorted[i]));
+            }
+        }
+
+        // some generation methods use a character not contained in the character set
+        let new_char = (0..=char::MAX as u32)
+            .filter_map(char::from_u32)
+            .find(|c| !char_set.contains(c))
+            .unwrap();
+        let indices = (0..char_set.len() as u8).collect();
+        let string_gen = StringGen::try_new(function, indices)?;
+
+        let mut symbols = char_set;
+        symbols.push(new_char);
+        Ok(Self {
+            string_gen,
+            symbols,
+        })
+    }
+
+    /// Makes the generation reproducible with the given seed, see [`StringGen::with_seed`]
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The seed from which the seeds of the strings are derived
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.string_gen = self.string_gen.with_seed(seed);
+        self
+    }
+
+    /// Creates a random string using the character set specified in the struct
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The length of the string to be generated
+    ///
+    /// # Panics
+    ///
+    /// * Panics if the length of the string to be generated is less than 1
+    /// * Panics if the target period of the generator is longer than the string
+    pub fn create_random_string(&self, n: usize) -> Vec<char> {
+        self.try_create_random_string(n)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Creates a random string using the character set specified in the struct, failing if the
+    /// length is not valid
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The length of the string to be generated
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::ZeroLength`] if the length of the string to be generated is less than 1
+    /// * [`Error::PeriodTooLong`] if the target period of the generator is longer than the string
+    pub fn try_create_random_string(&self, n: usize) -> Result<Vec<char>, Error> {
+        let indices = self.string_gen.try_create_random_string(n)?;
+        Ok(indices
+            .into_iter()
+            .map(|index| self.symbols[index as usize])
+            .collect())
     }
 }
@@ -7,9 +7,12 @@ use plotters::{
     series::LineSeries,
     style::{AsRelative, Color, IntoFont, BLACK, WHITE},
 };
-use chrono_probe::input::{distribution::Distribution, Input, InputBuilder};
+use chrono_probe::input::{distribution::Distribution, Input, InputBuilder, InputSet};
 
-use crate::{algorithms::period_smart, input::InputString};
+use crate::{
+    algorithms::{Algorithm, PeriodSolver},
+    input::InputString,
+};
 
 /// Plot some graphs for input generation analysis:
 /// * A graph comparing the fractional period for each input generation method as the string length increases.
@@ -18,6 +21,7 @@ use crate::{algorithms::period_smart, input::InputString};
 /// # Arguments
 ///
 /// * `input_gen` - The input generation methods to compare.
+/// * `seed` - The seed of the input generation, shown in the graphs if any.
 pub fn input_analysis<D: Distribution>(
     // The input generation methods to compare.
     input_gen: Vec<(InputBuilder<InputString, D>, &str)>,
@@ -27,6 +31,8 @@ pub fn input_analysis<D: Distribution>(
     n: usize,
     // The number of strings generated for each string length.
     repetitions: usize,
+    // The seed of the input generation.
+    seed: Option<u64>,
 ) {
     // ---------------- //
     // COMPARISON GRAPH //
@@ -42,7 +48,7 @@ pub fn input_analysis<D: Distribution>(
 
     lower
         .titled(
-            "Input strings analysis",
+            &seeded_title("Input strings analysis", seed),
             ("sans-serif", 10).into_font().color(&BLACK.mix(0.5)),
         )
         .unwrap();
@@ -68,18 +74,20 @@ pub fn input_analysis<D: Distribution>(
     for (i, (input, name)) in input_gen.iter().enumerate() {
         // Build the strings.
         let strings = input.build_with_repetitions(n, repetitions);
+        let periods = periods(&strings);
 
         let color = Palette99::pick(i).mix(0.9);
 
         // For each string length, calculate the mean fractional period and draw a line.
         chart
             .draw_series(LineSeries::new(
-                strings.inputs.iter().map(|same_size_strings| {
-                    (
-                        same_size_strings[0].get_size(),
-                        mean(same_size_strings.iter().map(|string| period_smart(string))),
-                    )
-                }),
+                strings
+                    .inputs
+                    .iter()
+                    .zip(periods)
+                    .map(|(same_size_strings, periods)| {
+                        (same_size_strings[0].get_size(), mean(periods.into_iter()))
+                    }),
                 color.stroke_width(3),
             ))
             .unwrap()
@@ -115,7 +123,8 @@ pub fn input_analysis<D: Distribution>(
             .inputs
             .iter()
             .flatten()
-            .map(|string| (string.get_size() as u32, period_smart(string) as u32))
+            .zip(periods(&strings).into_iter().flatten())
+            .map(|(string, period)| (string.get_size() as u32, period as u32))
         {
             prob_analysis.push(s.0 - s.1);
         }
@@ -171,7 +180,7 @@ pub fn input_analysis<D: Distribution>(
             .x_label_area_size(35)
             .y_label_area_size(40)
             .margin(5)
-            .caption("Histogram Test", ("sans-serif", 50.0))
+            .caption(seeded_title("Histogram Test", seed), ("sans-serif", 50.0))
             .build_cartesian_2d((0u32..max_x).into_segmented(), 0u32..max_y)
             .unwrap();
 
@@ -204,6 +213,146 @@ pub fn input_analysis<D: Distribution>(
     println!("Input generation comparison results saved to {}", path);
 }
 
+/// Plot a graph comparing the mean number of character comparisons performed by an algorithm for
+/// each input generation method as the string length increases, together with a linear bound.
+///
+/// # Arguments
+///
+/// * `input_gen` - The input generation methods to compare.
+/// * `algorithm` - The instrumented algorithm, returning the number of comparisons performed (see
+///   [`comparisons`](crate::comparisons)).
+/// * `bound` - The constant `c` of the bound `c * n` to be drawn, if any.
+/// * `path` - Where the graph will be saved.
+/// * `seed` - The seed of the input generation, shown in the graph if any.
+pub fn comparisons_analysis<D: Distribution>(
+    input_gen: Vec<(InputBuilder<InputString, D>, &str)>,
+    algorithm: &Algorithm,
+    bound: Option<usize>,
+    path: &str,
+    // The number of different string lengths to generate.
+    n: usize,
+    // The number of strings generated for each string length.
+    repetitions: usize,
+    // The seed of the input generation.
+    seed: Option<u64>,
+) {
+    // For each input generation method, calculate the mean number of comparisons for each string
+    // length.
+    let mut series = Vec::new();
+    for (input, name) in input_gen.iter() {
+        let strings = input.build_with_repetitions(n, repetitions);
+        let mut points = strings
+            .inputs
+            .iter()
+            .map(|same_size_strings| {
+                (
+                    same_size_strings[0].get_size(),
+                    mean(same_size_strings.iter().map(algorithm.function)),
+                )
+            })
+            .collect::<Vec<_>>();
+        points.sort();
+        series.push((points, *name));
+    }
+
+    let max_x = series
+        .iter()
+        .flat_map(|(points, _)| points.iter().map(|point| point.0))
+        .max()
+        .unwrap_or(0)
+        + 1;
+    let mut max_y = series
+        .iter()
+        .flat_map(|(points, _)| points.iter().map(|point| point.1))
+        .max()
+        .unwrap_or(0)
+        + 1;
+    if let Some(c) = bound {
+        max_y = max_y.max(c * max_x);
+    }
+
+    // Create the graph.
+    let root = SVGBackend::new(path, (1024, 768)).into_drawing_area();
+    root.fill(&WHITE).unwrap();
+
+    let caption = seeded_title(
+        &format!("Character comparisons of {}", algorithm.name),
+        seed,
+    );
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption(caption, ("sans-serif", (5).percent_height()))
+        .set_label_area_size(LabelAreaPosition::Left, (8).percent())
+        .set_label_area_size(LabelAreaPosition::Bottom, (4).percent())
+        .margin((1).percent())
+        .build_cartesian_2d(0usize..max_x, 0usize..max_y)
+        .unwrap();
+
+    chart
+        .configure_mesh()
+        .x_desc("String length")
+        .y_desc("Comparisons")
+        .draw()
+        .unwrap();
+
+    for (i, (points, name)) in series.into_iter().enumerate() {
+        let color = Palette99::pick(i).mix(0.9);
+        chart
+            .draw_series(LineSeries::new(points, color.stroke_width(3)))
+            .unwrap()
+            .label(name)
+            .legend(move |(x, y)| Rectangle::new([(x, y - 5), (x + 10, y + 5)], color.filled()));
+    }
+
+    if let Some(c) = bound {
+        chart
+            .draw_series(LineSeries::new(
+                [(0, 0), (max_x, c * max_x)],
+                BLACK.stroke_width(1),
+            ))
+            .unwrap()
+            .label(format!("{c}n"))
+            .legend(|(x, y)| Rectangle::new([(x, y - 1), (x + 10, y + 1)], BLACK.filled()));
+    }
+
+    chart
+        .configure_series_labels()
+        .border_style(BLACK)
+        .position(SeriesLabelPosition::UpperLeft)
+        .draw()
+        .unwrap();
+
+    // To avoid the IO failure being ignored silently, we manually call the present function.
+    root.present().expect(
+        "Unable to write result to file, please make sure 'results' dir exists under current dir",
+    );
+    println!(
+        "Comparisons analysis of the algorithm {} saved to {}",
+        algorithm.name, path
+    );
+}
+
+/// Computes the fractional periods of the strings, keeping the structure of the input set.
+#[cfg(not(feature = "parallel"))]
+fn periods(strings: &InputSet<InputString>) -> Vec<Vec<usize>> {
+    PeriodSolver::new().evaluate(strings)
+}
+
+/// Computes the fractional periods of the strings in parallel, keeping the structure of the input
+/// set.
+#[cfg(feature = "parallel")]
+fn periods(strings: &InputSet<InputString>) -> Vec<Vec<usize>> {
+    PeriodSolver::par_evaluate(strings)
+}
+
+/// Appends the seed of the input generation, if any, to the title of a graph.
+fn seeded_title(title: &str, seed: Option<u64>) -> String {
+    match seed {
+        Some(seed) => format!("{title} (seed {seed})"),
+        None => title.to_string(),
+    }
+}
+
 fn mean(values: impl Iterator<Item = usize>) -> usize {
     let mut mean = 0.0;
     for (i, value) in values.enumerate() {
@@ -1,3 +1,10 @@
 pub mod algorithms;
+pub mod comparisons;
+pub mod error;
 pub mod input;
-pub mod input_plot;
\ No newline at end of file
+pub mod input_plot;
+pub mod lyndon;
+pub mod markov;
+pub mod matching;
+pub mod runs;
+pub mod simd;
\ No newline at end of file
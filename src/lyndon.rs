@@ -0,0 +1,129 @@
+use crate::algorithms::Algorithm;
+use crate::input::InputString;
+
+/// Computes the Lyndon factorization of the string and returns the number of factors.
+/// Time complexity: Θ(n)
+pub const LYNDON_FACTORS: Algorithm = Algorithm {
+    name: "lyndon factors",
+    function: lyndon_factors,
+};
+
+/// Computes the Lyndon factorization of a string in linear time and constant extra space with
+/// Duval's algorithm.
+///
+/// The string is factorized as `s = w1 w2 ... wk`, where every factor is a Lyndon word (a string
+/// strictly smaller than all its proper suffixes) and `w1 ≥ w2 ≥ ... ≥ wk`. The factorization is
+/// returned as the ending positions (exclusive) of the factors, so the last one is the length of
+/// the string.
+///
+/// While scanning, the inner loop of the algorithm maintains the period `j - k` of the current
+/// prefix of a power of a Lyndon word, which makes it a period computation in disguise.
+///
+/// # Arguments
+///
+/// * `s` - The string to be factorized
+///
+/// # Examples
+///
+/// ```
+/// use fractional_period::{input::InputString, lyndon::lyndon_factorization};
+///
+/// let s: InputString = "abaababaab".try_into().unwrap();
+/// // ab | aabab | aab
+/// assert_eq!(lyndon_factorization(&s), vec![2, 7, 10]);
+/// ```
+pub fn lyndon_factorization(s: &[u8]) -> Vec<usize> {
+    let n = s.len();
+    let mut ends = Vec::new();
+
+    // start of the part of the string still to be factorized
+    let mut i = 0;
+    while i < n {
+        // s[i..j] is a prefix of a power of a Lyndon word of length j - k
+        let mut j = i + 1;
+        let mut k = i;
+        while j < n && s[k] <= s[j] {
+            if s[k] < s[j] {
+                // s[i..=j] is a Lyndon word
+                k = i;
+            } else {
+                // the period j - k is preserved
+                k += 1;
+            }
+            j += 1;
+        }
+        // output the complete repetitions of the Lyndon word
+        while i <= k {
+            i += j - k;
+            ends.push(i);
+        }
+    }
+    ends
+}
+
+/// Returns true if the string is a Lyndon word, that is it is non empty and strictly smaller than
+/// all its proper suffixes.
+///
+/// # Arguments
+///
+/// * `s` - The string to be analyzed
+pub fn is_lyndon_word(s: &[u8]) -> bool {
+    lyndon_factorization(s) == [s.len()]
+}
+
+/// Returns the starting position of the lexicographically minimal rotation of a string, in linear
+/// time and constant extra space.
+///
+/// The minimal rotation starts with the last Lyndon factor of `ss` (the string concatenated with
+/// itself) starting in the first half, so it is found by running Duval's algorithm on `ss`
+/// without building it. If the string is not primitive the smallest starting position is
+/// returned.
+///
+/// # Arguments
+///
+/// * `s` - The string to be analyzed
+///
+/// # Examples
+///
+/// ```
+/// use fractional_period::{input::InputString, lyndon::minimal_rotation};
+///
+/// let s: InputString = "baabab".try_into().unwrap();
+/// let start = minimal_rotation(&s);
+/// assert_eq!(start, 1);
+/// assert_eq!([&s[start..], &s[..start]].concat(), b"aababb");
+/// ```
+pub fn minimal_rotation(s: &[u8]) -> usize {
+    let n = s.len();
+    // character at position i of ss
+    let char = |i: usize| s[i % n];
+
+    let mut rotation = 0;
+    let mut i = 0;
+    while i < n {
+        rotation = i;
+        let mut j = i + 1;
+        let mut k = i;
+        while j < 2 * n && char(k) <= char(j) {
+            if char(k) < char(j) {
+                k = i;
+            } else {
+                k += 1;
+            }
+            j += 1;
+        }
+        while i <= k {
+            i += j - k;
+        }
+    }
+    rotation
+}
+
+/// Returns the number of factors of the Lyndon factorization of a string.
+///
+/// # Arguments
+///
+/// * `s` - The string to be analyzed
+pub fn lyndon_factors(s: &InputString) -> usize {
+    lyndon_factorization(s).len()
+}
@@ -0,0 +1,321 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::Rng;
+
+use crate::error::Error;
+
+/// A context of a [`MarkovModel`], with the characters following it and their weights.
+pub type Transition<'a> = (&'a [u8], &'a [(u8, f64)]);
+
+/// An order-k Markov chain on ascii characters: the probability of every character of a string
+/// only depends on the k characters preceding it.
+///
+/// A model of order 0 is a frequency table, see [`MarkovModel::from_frequencies`]. Models of
+/// higher order are trained on a sample, e.g. a text, a DNA sequence or a log file, see
+/// [`MarkovModel::train`], or given by their transitions, see [`MarkovModel::from_transitions`].
+/// The strings are generated by a [`StringGen`](crate::input::StringGen) created with
+/// [`StringGen::from_model`](crate::input::StringGen::from_model).
+///
+/// # Examples
+///
+/// ```
+/// use fractional_period::{algorithms::period_smart, markov::MarkovModel};
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// // every pair of characters of the sample is always followed by the same character
+/// let model = MarkovModel::train(b"abcabcabc", 2).unwrap();
+/// let s = model.generate(100, &mut StdRng::seed_from_u64(0));
+/// assert_eq!(period_smart(&s), 3);
+/// ```
+#[derive(Clone, Debug)]
+pub struct MarkovModel {
+    order: usize,
+    // the contexts from which the generation starts
+    contexts: Vec<Vec<u8>>,
+    context_weights: WeightedIndex<f64>,
+    // the characters following each context, with their weights
+    transitions: HashMap<Vec<u8>, (Vec<u8>, WeightedIndex<f64>)>,
+}
+
+impl MarkovModel {
+    /// Creates a model of order 0 generating every character independently with the given
+    /// relative frequency.
+    ///
+    /// # Arguments
+    ///
+    /// * `frequencies` - The characters with their frequencies, which need not sum to 1
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::EmptyAlphabet`] if no character is given
+    /// * [`Error::NonAsciiSymbol`] if a character is not ascii
+    /// * [`Error::DuplicateSymbol`] if a character is given more than once
+    /// * [`Error::InvalidFrequencies`] if a frequency is negative or not finite, or they are all 0
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractional_period::markov::MarkovModel;
+    ///
+    /// // the nucleotides of a GC-rich DNA sequence
+    /// let frequencies = [(b'A', 0.2), (b'C', 0.3), (b'G', 0.3), (b'T', 0.2)];
+    /// let model = MarkovModel::from_frequencies(&frequencies).unwrap();
+    /// assert_eq!(model.symbols(), b"ACGT");
+    /// ```
+    pub fn from_frequencies(frequencies: &[(u8, f64)]) -> Result<Self, Error> {
+        Self::from_transitions(0, &[(&[], frequencies)])
+    }
+
+    /// Creates a model of the given order from the weighted characters following every context.
+    ///
+    /// The generation starts from a context drawn uniformly, so every context reached by
+    /// appending a character of non zero weight to a context, and dropping its first character,
+    /// must have its own transitions.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - The number of characters the probability of the next one depends on
+    /// * `transitions` - The contexts of `order` characters, with the characters following them
+    ///   and their weights, which need not sum to 1
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::EmptyAlphabet`] if no context is given, or no character follows a context
+    /// * [`Error::NonAsciiSymbol`] if a character of a context or following it is not ascii
+    /// * [`Error::DuplicateSymbol`] if a character follows a context more than once
+    /// * [`Error::InvalidFrequencies`] if a weight is negative or not finite, or the weights of a
+    ///   context are all 0
+    /// * [`Error::ContextLength`] if the length of a context is not the order
+    /// * [`Error::DuplicateContext`] if a context is given more than once
+    /// * [`Error::MissingContext`] if a context reached during the generation has no transitions
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fractional_period::{algorithms::period_smart, error::Error, markov::MarkovModel};
+    /// use rand::{rngs::StdRng, SeedableRng};
+    ///
+    /// // a character is never followed by itself
+    /// let model =
+    ///     MarkovModel::from_transitions(1, &[(b"a", &[(b'b', 1.0)]), (b"b", &[(b'a', 1.0)])])
+    ///         .unwrap();
+    /// let s = model.generate(100, &mut StdRng::seed_from_u64(0));
+    /// assert_eq!(period_smart(&s), 2);
+    ///
+    /// // the generation would get stuck after the character b
+    /// let model = MarkovModel::from_transitions(1, &[(b"a", &[(b'a', 1.0), (b'b', 1.0)])]);
+    /// assert_eq!(model.err(), Some(Error::MissingContext("b".to_string())));
+    /// ```
+    pub fn from_transitions(order: usize, transitions: &[Transition]) -> Result<Self, Error> {
+        if transitions.is_empty() {
+            return Err(Error::EmptyAlphabet);
+        }
+
+        let mut table = HashMap::with_capacity(transitions.len());
+        for &(context, frequencies) in transitions {
+            if context.len() != order {
+                return Err(Error::ContextLength {
+                    order,
+                    actual: context.len(),
+                });
+            }
+            if let Some(&symbol) = context.iter().find(|symbol| !symbol.is_ascii()) {
+                return Err(Error::NonAsciiSymbol(char::from(symbol)));
+            }
+            let next = next_symbols(frequencies)?;
+            if table.insert(context.to_vec(), next).is_some() {
+                return Err(Error::DuplicateContext(
+                    String::from_utf8_lossy(context).into_owned(),
+                ));
+            }
+        }
+
+        // sorting the contexts makes the generation and the errors reproducible
+        let mut contexts = table.keys().cloned().collect::<Vec<_>>();
+        contexts.sort();
+
+        // the generation gets stuck on a context without transitions
+        for context in &contexts {
+            for &symbol in &table[context].0 {
+                let reached = [&context[..], &[symbol]].concat();
+                if !table.contains_key(&reached[1..]) {
+                    return Err(Error::MissingContext(
+                        String::from_utf8_lossy(&reached[1..]).into_owned(),
+                    ));
+                }
+            }
+        }
+
+        Ok(Self {
+            order,
+            context_weights: weighted_index(vec![1.0; contexts.len()])?,
+            contexts,
+            transitions: table,
+        })
+    }
+
+    /// Trains a model of the given order on a sample, counting how many times every character
+    /// follows every context of `order` characters.
+    ///
+    /// The sample is considered circular, so that every context of the sample is followed by at
+    /// least one character and the generation never gets stuck.
+    ///
+    /// # Arguments
+    ///
+    /// * `sample` - The string the model is trained on
+    /// * `order` - The number of characters the probability of the next one depends on
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::EmptyString`] if the sample is empty
+    /// * [`Error::NonAsciiByte`] if the sample contains a non ascii byte
+    /// * [`Error::SampleTooShort`] if the sample is not longer than the order
+    pub fn train(sample: &[u8], order: usize) -> Result<Self, Error> {
+        let n = sample.len();
+        if n == 0 {
+            return Err(Error::EmptyString);
+        }
+        if let Some(position) = sample.iter().position(|byte| !byte.is_ascii()) {
+            return Err(Error::NonAsciiByte {
+                position,
+                byte: sample[position],
+            });
+        }
+        if n <= order {
+            return Err(Error::SampleTooShort {
+                required: order + 1,
+                actual: n,
+            });
+        }
+
+        // the number of occurrences of every context and of every character following it
+        let mut counts: HashMap<Vec<u8>, (u64, HashMap<u8, u64>)> = HashMap::new();
+        for i in 0..n {
+            let context = (i..i + order).map(|j| sample[j % n]).collect::<Vec<_>>();
+            let next = sample[(i + order) % n];
+            let (count, next_counts) = counts.entry(context).or_default();
+            *count += 1;
+            *next_counts.entry(next).or_default() += 1;
+        }
+
+        // sorting the contexts and the characters makes the generation reproducible
+        let mut counts = counts.into_iter().collect::<Vec<_>>();
+        counts.sort_by(|(a, _), (b, _)| a.cmp(b));
+        let mut contexts = Vec::with_capacity(counts.len());
+        let mut context_weights = Vec::with_capacity(counts.len());
+        let mut transitions = HashMap::with_capacity(counts.len());
+        for (context, (count, next_counts)) in counts {
+            let mut next_counts = next_counts.into_iter().collect::<Vec<_>>();
+            next_counts.sort();
+            let (symbols, weights): (Vec<u8>, Vec<f64>) = next_counts
+                .into_iter()
+                .map(|(symbol, count)| (symbol, count as f64))
+                .unzip();
+            transitions.insert(context.clone(), (symbols, weighted_index(weights)?));
+            contexts.push(context);
+            context_weights.push(count as f64);
+        }
+
+        Ok(Self {
+            order,
+            contexts,
+            context_weights: weighted_index(context_weights)?,
+            transitions,
+        })
+    }
+
+    /// Trains a model of the given order on the content of a file, see [`MarkovModel::train`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file containing the sample
+    /// * `order` - The number of characters the probability of the next one depends on
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::Io`] if the file cannot be read
+    /// * The errors of [`MarkovModel::train`]
+    pub fn from_file(path: impl AsRef<Path>, order: usize) -> Result<Self, Error> {
+        let sample = fs::read(path).map_err(|error| Error::Io(error.to_string()))?;
+        Self::train(&sample, order)
+    }
+
+    /// Returns the order of the model.
+    pub fn order(&self) -> usize {
+        self.order
+    }
+
+    /// Returns the characters the model can generate, sorted.
+    pub fn symbols(&self) -> Vec<u8> {
+        let mut symbols = self
+            .transitions
+            .values()
+            .flat_map(|(symbols, _)| symbols.iter().copied())
+            .collect::<Vec<_>>();
+        symbols.sort();
+        symbols.dedup();
+        symbols
+    }
+
+    /// Generates a string of length `n`: the first `order` characters are a context of the sample
+    /// drawn with its frequency, or any context for a model created from its transitions, every
+    /// following character is drawn given the previous ones.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The length of the string to be generated
+    /// * `rng` - The random number generator
+    pub fn generate<R: Rng + ?Sized>(&self, n: usize, rng: &mut R) -> Vec<u8> {
+        let mut s = self.contexts[self.context_weights.sample(rng)].clone();
+        s.reserve(n.saturating_sub(s.len()));
+        while s.len() < n {
+            let (symbols, weights) = &self.transitions[&s[s.len() - self.order..]];
+            s.push(symbols[weights.sample(rng)]);
+        }
+        s.truncate(n);
+        s
+    }
+}
+
+/// Returns the characters of non zero frequency with the distribution choosing one of them.
+fn next_symbols(frequencies: &[(u8, f64)]) -> Result<(Vec<u8>, WeightedIndex<f64>), Error> {
+    if frequencies.is_empty() {
+        return Err(Error::EmptyAlphabet);
+    }
+    if let Some(&(symbol, _)) = frequencies.iter().find(|(symbol, _)| !symbol.is_ascii()) {
+        return Err(Error::NonAsciiSymbol(char::from(symbol)));
+    }
+
+    // checking for repetitions in the characters
+    let mut symbols = frequencies
+        .iter()
+        .map(|&(symbol, _)| symbol)
+        .collect::<Vec<_>>();
+    symbols.sort();
+    for i in 1..symbols.len() {
+        if symbols[i] == symbols[i - 1] {
+            return Err(Error::DuplicateSymbol(char::from(symbols[i])));
+        }
+    }
+
+    let weights = frequencies.iter().map(|&(_, weight)| weight).collect();
+    weighted_index(weights)?;
+    // the characters with frequency 0 are never generated
+    let (symbols, weights): (Vec<u8>, Vec<f64>) = frequencies
+        .iter()
+        .copied()
+        .filter(|&(_, weight)| weight > 0.0)
+        .unzip();
+    Ok((symbols, weighted_index(weights)?))
+}
+
+/// Creates the distribution choosing an index with the given weights.
+fn weighted_index(weights: Vec<f64>) -> Result<WeightedIndex<f64>, Error> {
+    if weights.iter().any(|weight| !weight.is_finite()) {
+        return Err(Error::InvalidFrequencies);
+    }
+    WeightedIndex::new(weights).map_err(|_| Error::InvalidFrequencies)
+}
@@ -0,0 +1,285 @@
+use crate::algorithms::{border_array, critical_factorization, Algorithm, CriticalFactorization};
+use crate::input::InputString;
+
+// Some predefined algorithms for measuring the string matching algorithms:
+
+/// Knuth–Morris–Pratt search of all the occurrences of the prefix of length ⌈√n⌉ of a string.
+/// Time complexity: Θ(n)
+pub const MATCHING_KMP: Algorithm = Algorithm {
+    name: "matching kmp",
+    function: kmp_prefix_occurrences,
+};
+
+/// Two-Way search of all the occurrences of the prefix of length ⌈√n⌉ of a string.
+/// Time complexity: Θ(n)
+pub const MATCHING_TWO_WAY: Algorithm = Algorithm {
+    name: "matching two way",
+    function: two_way_prefix_occurrences,
+};
+
+/// Returns the length of the pattern searched by the predefined matching algorithms in a string
+/// of length `n`, that is ⌈√n⌉.
+fn pattern_length(n: usize) -> usize {
+    let mut length = (n as f64).sqrt() as usize;
+    while length * length < n {
+        length += 1;
+    }
+    length
+}
+
+/// Counts the occurrences in `s` of its prefix of length ⌈√n⌉ with the Knuth–Morris–Pratt
+/// algorithm.
+///
+/// # Arguments
+///
+/// * `s` - The string to be analyzed
+pub fn kmp_prefix_occurrences(s: &InputString) -> usize {
+    let pattern = &s[..pattern_length(s.len())];
+    Kmp::new(pattern).find_all(s).count()
+}
+
+/// Counts the occurrences in `s` of its prefix of length ⌈√n⌉ with the Two-Way algorithm.
+///
+/// # Arguments
+///
+/// * `s` - The string to be analyzed
+pub fn two_way_prefix_occurrences(s: &InputString) -> usize {
+    let pattern = &s[..pattern_length(s.len())];
+    TwoWay::new(pattern).find_all(s).count()
+}
+
+/// Knuth–Morris–Pratt string matching, based on the border array of the pattern.
+///
+/// # Examples
+///
+/// ```
+/// use fractional_period::{input::InputString, matching::Kmp};
+///
+/// let text: InputString = "abaababaab".try_into().unwrap();
+/// let kmp = Kmp::new(b"aba");
+/// assert_eq!(kmp.find(&text), Some(0));
+/// assert_eq!(kmp.find_all(&text).collect::<Vec<_>>(), vec![0, 3, 5]);
+/// ```
+pub struct Kmp<'a> {
+    pattern: &'a [u8],
+    // border array of the pattern
+    borders: Vec<usize>,
+}
+
+impl<'a> Kmp<'a> {
+    /// Creates a new searcher for the given pattern, computing its border array.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The pattern to be searched
+    pub fn new(pattern: &'a [u8]) -> Self {
+        Kmp {
+            pattern,
+            borders: border_array(pattern),
+        }
+    }
+
+    /// Returns the starting position of the first occurrence of the pattern in `text`, if any.
+    pub fn find(&self, text: &[u8]) -> Option<usize> {
+        self.find_all(text).next()
+    }
+
+    /// Returns an iterator over the starting positions of all the (possibly overlapping)
+    /// occurrences of the pattern in `text`, in increasing order.
+    pub fn find_all<'b>(&'b self, text: &'b [u8]) -> KmpMatches<'a, 'b> {
+        KmpMatches {
+            searcher: self,
+            text,
+            position: 0,
+            matched: 0,
+        }
+    }
+}
+
+/// Iterator over the occurrences found by [`Kmp::find_all`].
+pub struct KmpMatches<'a, 'b> {
+    searcher: &'b Kmp<'a>,
+    text: &'b [u8],
+    // position of the next character of the text to be read
+    position: usize,
+    // length of the prefix of the pattern matching the text before position
+    matched: usize,
+}
+
+impl Iterator for KmpMatches<'_, '_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Kmp { pattern, borders } = self.searcher;
+        let m = pattern.len();
+
+        // the empty pattern occurs at every position
+        if m == 0 {
+            if self.position > self.text.len() {
+                return None;
+            }
+            self.position += 1;
+            return Some(self.position - 1);
+        }
+
+        while self.position < self.text.len() {
+            let char = self.text[self.position];
+            self.position += 1;
+
+            // follow the border chain until the match can be extended
+            while self.matched > 0 && pattern[self.matched] != char {
+                self.matched = borders[self.matched - 1];
+            }
+            if pattern[self.matched] == char {
+                self.matched += 1;
+            }
+
+            if self.matched == m {
+                self.matched = borders[m - 1];
+                return Some(self.position - m);
+            }
+        }
+        None
+    }
+}
+
+/// Two-Way string matching (Crochemore–Perrin), based on the critical factorization of the
+/// pattern, using constant extra space.
+///
+/// # Examples
+///
+/// ```
+/// use fractional_period::{input::InputString, matching::TwoWay};
+///
+/// let text: InputString = "abaababaab".try_into().unwrap();
+/// let two_way = TwoWay::new(b"aba");
+/// assert_eq!(two_way.find(&text), Some(0));
+/// assert_eq!(two_way.find_all(&text).collect::<Vec<_>>(), vec![0, 3, 5]);
+/// ```
+pub struct TwoWay<'a> {
+    pattern: &'a [u8],
+    // critical position of the pattern
+    critical_position: usize,
+    // the period of the pattern if it is periodic, a lower bound of it otherwise
+    period: usize,
+    // whether the prefix of the pattern before the critical position is a suffix of the first
+    // period after it
+    periodic: bool,
+}
+
+impl<'a> TwoWay<'a> {
+    /// Creates a new searcher for the given pattern, computing its critical factorization.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The pattern to be searched
+    pub fn new(pattern: &'a [u8]) -> Self {
+        let CriticalFactorization {
+            position: critical_position,
+            suffix_period: period,
+        } = critical_factorization(pattern);
+        // the check is skipped for the empty pattern, whose suffix period is 1 by convention
+        let periodic = critical_position + period <= pattern.len()
+            && pattern[..critical_position] == pattern[period..period + critical_position];
+        let period = if periodic {
+            period
+        } else {
+            critical_position.max(pattern.len() - critical_position) + 1
+        };
+        TwoWay {
+            pattern,
+            critical_position,
+            period,
+            periodic,
+        }
+    }
+
+    /// Returns the critical position of the pattern.
+    pub fn critical_position(&self) -> usize {
+        self.critical_position
+    }
+
+    /// Returns the period of the pattern if it is periodic (see [`TwoWay::is_periodic`]),
+    /// otherwise a lower bound of it greater than half the length of the pattern.
+    pub fn period(&self) -> usize {
+        self.period
+    }
+
+    /// Returns true if the prefix of the pattern before the critical position is a suffix of the
+    /// first period after it, in which case [`TwoWay::period`] is the exact period.
+    pub fn is_periodic(&self) -> bool {
+        self.periodic
+    }
+
+    /// Returns the starting position of the first occurrence of the pattern in `text`, if any.
+    pub fn find(&self, text: &[u8]) -> Option<usize> {
+        self.find_all(text).next()
+    }
+
+    /// Returns an iterator over the starting positions of all the (possibly overlapping)
+    /// occurrences of the pattern in `text`, in increasing order.
+    pub fn find_all<'b>(&'b self, text: &'b [u8]) -> TwoWayMatches<'a, 'b> {
+        TwoWayMatches {
+            searcher: self,
+            text,
+            position: 0,
+            memory: 0,
+        }
+    }
+}
+
+/// Iterator over the occurrences found by [`TwoWay::find_all`].
+pub struct TwoWayMatches<'a, 'b> {
+    searcher: &'b TwoWay<'a>,
+    text: &'b [u8],
+    // position of the text where the pattern is currently aligned
+    position: usize,
+    // length of the prefix of the pattern known to match at the current position
+    memory: usize,
+}
+
+impl Iterator for TwoWayMatches<'_, '_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let TwoWay {
+            pattern,
+            critical_position,
+            period,
+            periodic,
+        } = *self.searcher;
+        let m = pattern.len();
+
+        'search: while self.position + m <= self.text.len() {
+            let window = &self.text[self.position..self.position + m];
+
+            // compare the right part of the pattern from left to right
+            for i in critical_position.max(self.memory)..m {
+                if pattern[i] != window[i] {
+                    self.position += i - critical_position + 1;
+                    self.memory = 0;
+                    continue 'search;
+                }
+            }
+
+            // compare the left part of the pattern from right to left
+            for i in (self.memory..critical_position).rev() {
+                if pattern[i] != window[i] {
+                    self.position += period;
+                    if periodic {
+                        self.memory = m - period;
+                    }
+                    continue 'search;
+                }
+            }
+
+            let start = self.position;
+            self.position += period;
+            if periodic {
+                self.memory = m - period;
+            }
+            return Some(start);
+        }
+        None
+    }
+}
@@ -0,0 +1,327 @@
+use crate::algorithms::Algorithm;
+use crate::input::InputString;
+
+/// Computes all the runs of the string and returns their number.
+/// Time complexity: Θ(n log n)
+pub const RUNS: Algorithm = Algorithm {
+    name: "runs",
+    function: runs_count,
+};
+
+/// A run (maximal repetition) of a string: the substring `s[start..end]` has minimum period
+/// `period`, it is at least two periods long and it can't be extended to the left or to the
+/// right keeping the same period.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Run {
+    /// The starting position of the run
+    pub start: usize,
+    /// The ending position (exclusive) of the run
+    pub end: usize,
+    /// The minimum period of the run
+    pub period: usize,
+}
+
+impl Run {
+    /// Returns the length of the run.
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Returns true if the run is empty, which never happens for the runs returned by [`runs`].
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns the (fractional) exponent of the run, that is its length divided by its period.
+    pub fn exponent(&self) -> f64 {
+        self.len() as f64 / self.period as f64
+    }
+}
+
+/// Computes all the runs of a string in O(n log n) time.
+///
+/// By the runs theorem every run with period `p` contains a Lyndon root: a Lyndon word of length
+/// `p`, with respect to one of the two orderings of the characters, that is the longest Lyndon
+/// word starting at its position. Therefore the runs are found by extending, with longest common
+/// extension queries, the longest Lyndon word starting at every position for both orderings.
+///
+/// # Arguments
+///
+/// * `s` - The string to be analyzed
+///
+/// # Examples
+///
+/// ```
+/// use fractional_period::{input::InputString, runs::{runs, Run}};
+///
+/// let s: InputString = "aabaabab".try_into().unwrap();
+/// assert_eq!(
+///     runs(&s),
+///     vec![
+///         Run { start: 0, end: 2, period: 1 },
+///         Run { start: 0, end: 7, period: 3 },
+///         Run { start: 3, end: 5, period: 1 },
+///         Run { start: 4, end: 8, period: 2 },
+///     ]
+/// );
+/// ```
+pub fn runs(s: &[u8]) -> Vec<Run> {
+    let n = s.len();
+    let forward = Lce::new(s);
+    let reversed_string = s.iter().rev().copied().collect::<Vec<_>>();
+    let backward = Lce::new(&reversed_string);
+
+    let mut runs = Vec::new();
+    for reversed in [false, true] {
+        // suffix i is smaller than suffix j with respect to the ordering
+        let less = |i: usize, j: usize| {
+            let l = forward.lce(i, j);
+            if j + l == n {
+                // suffix j is a prefix of suffix i
+                return false;
+            }
+            (s[i + l] < s[j + l]) != reversed
+        };
+
+        // lyndon[i] is the length of the longest Lyndon word starting at i
+        let mut lyndon = vec![0; n];
+        for i in (0..n).rev() {
+            let mut j = i + 1;
+            while j < n && less(i, j) {
+                j += lyndon[j];
+            }
+            lyndon[i] = j - i;
+        }
+
+        for (i, &period) in lyndon.iter().enumerate() {
+            let right = if i + period < n {
+                forward.lce(i, i + period)
+            } else {
+                0
+            };
+            // longest common suffix of s[..i] and s[..i + period]
+            let left = if i > 0 {
+                backward.lce(n - i, n - i - period)
+            } else {
+                0
+            };
+            if left + right >= period {
+                runs.push(Run {
+                    start: i - left,
+                    end: i + period + right,
+                    period,
+                });
+            }
+        }
+    }
+
+    runs.sort_unstable();
+    runs.dedup();
+    runs
+}
+
+/// An occurrence of a square `ww` in a string, that is `s[start..start + 2 * period]` where `w`
+/// has length `period`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Square {
+    /// The starting position of the square
+    pub start: usize,
+    /// The length of the root `w` of the square
+    pub period: usize,
+}
+
+/// Returns true if the string contains a square, that is a substring of the form `ww` with `w`
+/// non empty.
+///
+/// # Arguments
+///
+/// * `s` - The string to be analyzed
+pub fn contains_square(s: &[u8]) -> bool {
+    contains_power(s, 2)
+}
+
+/// Returns true if the string contains a `k`-th power, that is a substring of the form `w^k` with
+/// `w` non empty.
+///
+/// A `k`-th power is contained in a run whose length is at least `k` times its period (the root
+/// of a power of a non primitive string is itself a power), so it is enough to look at the runs.
+///
+/// # Arguments
+///
+/// * `s` - The string to be analyzed
+/// * `k` - The exponent of the power
+///
+/// # Panics
+///
+/// * Panics if `k` is less than 2
+///
+/// # Examples
+///
+/// ```
+/// use fractional_period::{input::InputString, runs::contains_power};
+///
+/// let s: InputString = "abaababaab".try_into().unwrap();
+/// assert!(contains_power(&s, 2));
+/// assert!(!contains_power(&s, 3));
+/// ```
+pub fn contains_power(s: &[u8], k: usize) -> bool {
+    assert!(k >= 2, "The exponent of the power must be at least 2");
+    runs(s).iter().any(|run| run.len() >= k * run.period)
+}
+
+/// Computes all the occurrences of primitively rooted squares in a string, that is squares `ww`
+/// with `w` primitive, sorted by starting position and then by period.
+///
+/// Since the minimum period of a primitively rooted square is the length of its root, every
+/// occurrence belongs to exactly one run with the same period. Hence the occurrences are
+/// enumerated from the runs, in O(n log n) time plus the number of occurrences (which is O(n log
+/// n) as well).
+///
+/// # Arguments
+///
+/// * `s` - The string to be analyzed
+///
+/// # Examples
+///
+/// ```
+/// use fractional_period::{input::InputString, runs::{primitively_rooted_squares, Square}};
+///
+/// let s: InputString = "aabab".try_into().unwrap();
+/// assert_eq!(
+///     primitively_rooted_squares(&s),
+///     vec![Square { start: 0, period: 1 }, Square { start: 1, period: 2 }]
+/// );
+/// ```
+pub fn primitively_rooted_squares(s: &[u8]) -> Vec<Square> {
+    let mut squares = Vec::new();
+    for run in runs(s) {
+        for start in run.start..=run.end - 2 * run.period {
+            squares.push(Square {
+                start,
+                period: run.period,
+            });
+        }
+    }
+    squares.sort_unstable();
+    squares
+}
+
+/// Returns the number of runs of a string.
+///
+/// # Arguments
+///
+/// * `s` - The string to be analyzed
+pub fn runs_count(s: &InputString) -> usize {
+    runs(s).len()
+}
+
+/// Longest common extension queries on the suffixes of a string in constant time, based on the
+/// suffix array, the LCP array and a sparse table for range minimum queries.
+struct Lce {
+    // rank of every suffix in the suffix array
+    rank: Vec<usize>,
+    // sparse[k][i] is the minimum of lcp[i..i + 2^k]
+    sparse: Vec<Vec<u32>>,
+}
+
+impl Lce {
+    fn new(s: &[u8]) -> Self {
+        let n = s.len();
+        let suffix_array = suffix_array(s);
+        let mut rank = vec![0; n];
+        for (i, &suffix) in suffix_array.iter().enumerate() {
+            rank[suffix] = i;
+        }
+
+        // Kasai's algorithm: lcp[i] is the longest common prefix of the suffixes of rank i and
+        // i + 1
+        let mut lcp = vec![0; n.saturating_sub(1)];
+        let mut h = 0;
+        for i in 0..n {
+            if rank[i] + 1 < n {
+                let j = suffix_array[rank[i] + 1];
+                while i + h < n && j + h < n && s[i + h] == s[j + h] {
+                    h += 1;
+                }
+                lcp[rank[i]] = h as u32;
+                h = h.saturating_sub(1);
+            } else {
+                h = 0;
+            }
+        }
+
+        let mut sparse = vec![lcp];
+        let mut width = 1;
+        while 2 * width <= sparse[0].len() {
+            let previous = sparse.last().unwrap();
+            let next = (0..previous.len() - width)
+                .map(|i| previous[i].min(previous[i + width]))
+                .collect();
+            sparse.push(next);
+            width *= 2;
+        }
+
+        Lce { rank, sparse }
+    }
+
+    /// Returns the length of the longest common prefix of the suffixes starting at `i` and `j`.
+    fn lce(&self, i: usize, j: usize) -> usize {
+        if i == j {
+            return self.rank.len() - i;
+        }
+        let (low, high) = if self.rank[i] < self.rank[j] {
+            (self.rank[i], self.rank[j])
+        } else {
+            (self.rank[j], self.rank[i])
+        };
+        let k = (usize::BITS - 1 - (high - low).leading_zeros()) as usize;
+        self.sparse[k][low].min(self.sparse[k][high - (1 << k)]) as usize
+    }
+}
+
+/// Computes the suffix array of a string by prefix doubling with counting sort in O(n log n)
+/// time.
+fn suffix_array(s: &[u8]) -> Vec<usize> {
+    let n = s.len();
+    let mut suffix_array = (0..n).collect::<Vec<_>>();
+    suffix_array.sort_unstable_by_key(|&i| s[i]);
+    let mut rank = s.iter().map(|&c| c as usize).collect::<Vec<_>>();
+    let mut new_rank = vec![0; n];
+    let mut by_second_key = Vec::with_capacity(n);
+    let mut count = vec![0; n.max(256) + 1];
+
+    let mut k = 1;
+    while k < n {
+        // sort by the rank of the suffix starting k positions later, the shorter suffixes first
+        by_second_key.clear();
+        by_second_key.extend(n - k..n);
+        by_second_key.extend(suffix_array.iter().filter(|&&i| i >= k).map(|&i| i - k));
+
+        // stable counting sort by the rank of the suffix itself
+        count.iter_mut().for_each(|c| *c = 0);
+        for &i in by_second_key.iter() {
+            count[rank[i] + 1] += 1;
+        }
+        for c in 1..count.len() {
+            count[c] += count[c - 1];
+        }
+        for &i in by_second_key.iter() {
+            suffix_array[count[rank[i]]] = i;
+            count[rank[i]] += 1;
+        }
+
+        // compute the ranks of the prefixes of length 2k
+        let key = |i: usize| (rank[i], if i + k < n { rank[i + k] + 1 } else { 0 });
+        new_rank[suffix_array[0]] = 0;
+        for j in 1..n {
+            let increment = (key(suffix_array[j - 1]) != key(suffix_array[j])) as usize;
+            new_rank[suffix_array[j]] = new_rank[suffix_array[j - 1]] + increment;
+        }
+        std::mem::swap(&mut rank, &mut new_rank);
+        if rank[suffix_array[n - 1]] == n - 1 {
+            break;
+        }
+        k *= 2;
+    }
+    suffix_array
+}
@@ -0,0 +1,206 @@
+use crate::algorithms::Algorithm;
+
+/// Variation of the naive algorithm comparing the string with its shifts using SIMD instructions.
+/// Time complexity: O(n<sup>2</sup>)
+pub const PERIOD_NAIVE_SIMD: Algorithm = Algorithm {
+    name: "period naive simd",
+    function: |s| period_naive_simd(&s[..]),
+};
+
+/// Variation of [`period_naive2`](crate::algorithms::period_naive2) comparing the string with its
+/// shifts using SIMD instructions when available.
+///
+/// The widest instruction set supported by the CPU is detected at runtime, falling back to a
+/// scalar comparison on the other architectures. Before comparing a shift vector by vector, its
+/// first and last characters are checked, so that most of the wrong shifts of a random string are
+/// discarded in constant time.
+///
+/// # Arguments
+///
+/// * `s` - The string to be analyzed
+///
+/// # Examples
+///
+/// ```
+/// use fractional_period::simd::period_naive_simd;
+///
+/// assert_eq!(period_naive_simd(b"abcabcab"), 3);
+/// assert_eq!(period_naive_simd(&[b'a'; 100]), 1);
+/// ```
+pub fn period_naive_simd(s: &[u8]) -> usize {
+    period_early_exit(s, equal_function())
+}
+
+/// Scalar version of [`period_naive_simd`]: the same shifts are discarded by the first and last
+/// character checks, and the other ones are compared one element at a time.
+///
+/// Unlike the SIMD version it is generic over the element type, so that its character
+/// comparisons can be counted with [`count_comparisons`](crate::comparisons::count_comparisons).
+///
+/// # Arguments
+///
+/// * `s` - The string to be analyzed
+pub fn period_naive_early_exit<T: Eq>(s: &[T]) -> usize {
+    period_early_exit(s, |a: &[T], b: &[T]| a == b)
+}
+
+/// Finds the period comparing the string with its shifts through the given function, after
+/// checking their first and last characters.
+fn period_early_exit<T: Eq>(s: &[T], equal: impl Fn(&[T], &[T]) -> bool) -> usize {
+    let n = s.len();
+    for i in 1..n {
+        // early exit: the shift must preserve the first and the last character
+        if s[i] != s[0] || s[n - 1 - i] != s[n - 1] {
+            continue;
+        }
+        if equal(&s[..n - i], &s[i..]) {
+            return i;
+        }
+    }
+    n
+}
+
+/// Returns the fastest function for comparing two byte slices of the same length supported by the
+/// CPU.
+fn equal_function() -> fn(&[u8], &[u8]) -> bool {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("avx2") {
+            return |a, b| {
+                // SAFETY: the CPU supports AVX2
+                unsafe { x86_64::equal_avx2(a, b) }
+            };
+        }
+        // SSE2 is always available on x86_64
+        x86_64::equal_sse2
+    }
+    #[cfg(target_arch = "aarch64")]
+    {
+        // NEON is always available on aarch64
+        aarch64::equal_neon
+    }
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+    {
+        equal_scalar
+    }
+}
+
+/// Compares two byte slices of the same length one byte at a time.
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+fn equal_scalar(a: &[u8], b: &[u8]) -> bool {
+    a == b
+}
+
+#[cfg(target_arch = "x86_64")]
+mod x86_64 {
+    use std::arch::x86_64::{
+        __m128i, __m256i, _mm256_cmpeq_epi8, _mm256_loadu_si256, _mm256_movemask_epi8,
+        _mm_cmpeq_epi8, _mm_loadu_si128, _mm_movemask_epi8,
+    };
+
+    /// Compares two byte slices of the same length 32 bytes at a time.
+    ///
+    /// # Safety
+    ///
+    /// The CPU must support AVX2.
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn equal_avx2(a: &[u8], b: &[u8]) -> bool {
+        debug_assert_eq!(a.len(), b.len());
+        let mut i = 0;
+        while i + 32 <= a.len() {
+            // SAFETY: both slices have at least 32 bytes starting from i, and the loads are
+            // unaligned
+            let (x, y) = unsafe {
+                (
+                    _mm256_loadu_si256(a.as_ptr().add(i) as *const __m256i),
+                    _mm256_loadu_si256(b.as_ptr().add(i) as *const __m256i),
+                )
+            };
+            if _mm256_movemask_epi8(_mm256_cmpeq_epi8(x, y)) != -1 {
+                return false;
+            }
+            i += 32;
+        }
+        a[i..] == b[i..]
+    }
+
+    /// Compares two byte slices of the same length 16 bytes at a time.
+    pub(super) fn equal_sse2(a: &[u8], b: &[u8]) -> bool {
+        debug_assert_eq!(a.len(), b.len());
+        let mut i = 0;
+        while i + 16 <= a.len() {
+            // SAFETY: SSE2 is always available on x86_64, both slices have at least 16 bytes
+            // starting from i, and the loads are unaligned
+            let equal = unsafe {
+                let x = _mm_loadu_si128(a.as_ptr().add(i) as *const __m128i);
+                let y = _mm_loadu_si128(b.as_ptr().add(i) as *const __m128i);
+                _mm_movemask_epi8(_mm_cmpeq_epi8(x, y)) == 0xffff
+            };
+            if !equal {
+                return false;
+            }
+            i += 16;
+        }
+        a[i..] == b[i..]
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+mod aarch64 {
+    use std::arch::aarch64::{vceqq_u8, vld1q_u8, vminvq_u8};
+
+    /// Compares two byte slices of the same length 16 bytes at a time.
+    pub(super) fn equal_neon(a: &[u8], b: &[u8]) -> bool {
+        debug_assert_eq!(a.len(), b.len());
+        let mut i = 0;
+        while i + 16 <= a.len() {
+            // SAFETY: NEON is always available on aarch64 and both slices have at least 16 bytes
+            // starting from i
+            let equal = unsafe {
+                let x = vld1q_u8(a.as_ptr().add(i));
+                let y = vld1q_u8(b.as_ptr().add(i));
+                vminvq_u8(vceqq_u8(x, y)) == u8::MAX
+            };
+            if !equal {
+                return false;
+            }
+            i += 16;
+        }
+        a[i..] == b[i..]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    /// Checks a comparison function against `==` on slices around the 16 and 32 byte boundaries,
+    /// equal or differing at a single position, and not aligned in memory.
+    #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+    fn check_equal(equal: impl Fn(&[u8], &[u8]) -> bool) {
+        for n in [0, 1, 15, 16, 17, 31, 32, 33, 47, 48, 49, 63, 64, 65] {
+            let buffer = (0..=n).map(|i| i as u8).collect::<Vec<_>>();
+            let a = &buffer[1..];
+            assert!(equal(a, a), "length {n}");
+            for i in 0..n {
+                let mut b = a.to_vec();
+                b[i] ^= 0x80;
+                assert_eq!(equal(a, &b), a == b, "length {n}, position {i}");
+            }
+        }
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    #[test]
+    fn test_equal_x86_64() {
+        check_equal(super::x86_64::equal_sse2);
+        if is_x86_feature_detected!("avx2") {
+            // SAFETY: the CPU supports AVX2
+            check_equal(|a, b| unsafe { super::x86_64::equal_avx2(a, b) });
+        }
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    #[test]
+    fn test_equal_aarch64() {
+        check_equal(super::aarch64::equal_neon);
+    }
+}
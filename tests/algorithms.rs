@@ -1,11 +1,18 @@
+mod common;
+
+use chrono_probe::input::InputSet;
+use common::all_strings;
 use fractional_period::{
-    algorithms::{Algorithm, PERIOD_NAIVE1, PERIOD_NAIVE2, PERIOD_SMART},
-    input::InputString,
+    algorithms::{
+        all_periods, border_array, critical_factorization, is_primitive, local_period,
+        maximal_suffix, period_naive1, period_naive2, period_smart, prefix_periods, primitive_root,
+        try_primitive_root, z_array, BorderArray, PeriodAlgorithm, PeriodSolver, Registry,
+        SuffixOrder, PERIOD_SMART,
+    },
+    error::Error,
+    input::{InputString, StringGen, StringGenFunction},
 };
 
-/// The algorithms to be tested
-const ALGORITHMS: [Algorithm; 3] = [PERIOD_NAIVE1, PERIOD_NAIVE2, PERIOD_SMART];
-
 /// Test the given period finding algorithms
 ///
 /// # Arguments
@@ -13,10 +20,10 @@ const ALGORITHMS: [Algorithm; 3] = [PERIOD_NAIVE1, PERIOD_NAIVE2, PERIOD_SMART];
 /// * `input` - The string to be analyzed
 /// * `expected` - The expected period
 /// * `algorithms` - The algorithms to be tested
-fn test_algorithms(input: InputString, expected: usize, algorithms: &[Algorithm]) {
-    for algorithm in algorithms {
-        let actual = (algorithm.function)(&input.clone());
-        assert_eq!(expected, actual);
+fn test_algorithms(input: InputString, expected: usize, algorithms: &mut Registry) {
+    for algorithm in algorithms.iter_mut() {
+        let actual = algorithm.period(&input);
+        assert_eq!(expected, actual, "{} on {:?}", algorithm.name(), input.0);
     }
 }
 
@@ -27,7 +34,7 @@ fn test_algorithms(input: InputString, expected: usize, algorithms: &[Algorithm]
 /// * `input` - The string to be analyzed
 /// * `expected` - The expected period
 fn test(input: InputString, expected: usize) {
-    test_algorithms(input, expected, &ALGORITHMS);
+    test_algorithms(input, expected, &mut Registry::builtin());
 }
 
 #[test]
@@ -87,4 +94,253 @@ test!(
     ("ababaababaababaa", 5),
     ("ababaababaababaaababaababaababa", 16),
     ("abbabaabbaababbabaababbaabbabaab", 24)
-);
\ No newline at end of file
+);
+
+/// Test that all the algorithms agree on every string of length up to 12 over a binary alphabet and
+/// of length up to 8 over a ternary alphabet
+#[test]
+fn test_cross_check() {
+    let mut algorithms = Registry::builtin();
+    for (alphabet, max_length) in [(&b"ab"[..], 12), (b"abc", 8)] {
+        for input in all_strings(alphabet, max_length) {
+            let input = InputString(input);
+            let expected = period_naive1(&input[..]);
+            test_algorithms(input, expected, &mut algorithms);
+        }
+    }
+}
+
+/// Test that all the algorithms agree on the edge cases: the period of the empty string is 0 and the
+/// period of a single character is 1
+#[test]
+fn test_edge_cases() {
+    test(InputString(Vec::new()), 0);
+    test("a".try_into().unwrap(), 1);
+    for algorithm in Registry::builtin().iter_mut() {
+        assert_eq!(algorithm.checked(&InputString(Vec::new())), None);
+        assert_eq!(algorithm.checked(&"aa".try_into().unwrap()), Some(1));
+    }
+    assert!(border_array::<u8>(&[]).is_empty());
+    assert!(prefix_periods::<u8>(&[]).is_empty());
+    assert!(z_array::<u8>(&[]).is_empty());
+    assert!(all_periods::<u8>(&[]).is_empty());
+}
+
+#[test]
+fn test_period_solver() {
+    let mut solver = PeriodSolver::new();
+    for input in ["abaababaab", "a", "", "abcabcab", "aaaaaaaaaaab", "aa"] {
+        assert_eq!(
+            solver.period(input.as_bytes()),
+            period_smart(input.as_bytes())
+        );
+        assert_eq!(
+            solver.border_array(input.as_bytes()),
+            border_array(input.as_bytes())
+        );
+    }
+}
+
+/// An input set of random strings of different lengths, some of them periodic
+fn input_set() -> InputSet<InputString> {
+    let string_gen = StringGen::new(StringGenFunction::CreateRandomString2, vec![b'a', b'b']);
+    InputSet {
+        inputs: (1..=20)
+            .map(|n| {
+                (0..10)
+                    .map(|_| InputString(string_gen.create_random_string(n * 10)))
+                    .collect()
+            })
+            .collect(),
+    }
+}
+
+#[test]
+fn test_evaluate() {
+    let input_set = input_set();
+    let periods = PERIOD_SMART.evaluate(&input_set);
+    assert_eq!(periods, PeriodSolver::new().evaluate(&input_set));
+    for (inputs, periods) in input_set.inputs.iter().zip(&periods) {
+        for (input, &period) in inputs.iter().zip(periods) {
+            assert_eq!(period, period_smart(&input[..]));
+        }
+    }
+    assert_eq!(
+        PERIOD_SMART.expected_value(&input_set),
+        PeriodSolver::new().expected_value(&input_set)
+    );
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn test_par_evaluate() {
+    let input_set = input_set();
+    let periods = PERIOD_SMART.evaluate(&input_set);
+    assert_eq!(PERIOD_SMART.par_evaluate(&input_set), periods);
+    assert_eq!(PeriodSolver::par_evaluate(&input_set), periods);
+    assert_eq!(
+        PERIOD_SMART.par_expected_value(&input_set),
+        PERIOD_SMART.expected_value(&input_set)
+    );
+    assert_eq!(
+        PeriodSolver::par_expected_value(&input_set),
+        PERIOD_SMART.expected_value(&input_set)
+    );
+}
+
+#[test]
+fn test_registry() {
+    let registry = Registry::builtin();
+    assert_eq!(registry.len(), registry.measurable().len() + 1);
+    assert!(registry.get("period solver").unwrap().function().is_none());
+    assert!(registry.get("period smart").is_some());
+    assert!(registry.get("period smarter").is_none());
+
+    let selected = Registry::builtin()
+        .select(&["period z", "period naive 1"])
+        .unwrap();
+    assert_eq!(
+        selected.names().collect::<Vec<_>>(),
+        ["period z", "period naive 1"]
+    );
+    assert_eq!(
+        Registry::builtin().select(&["period smarter"]).err(),
+        Some(Error::UnknownAlgorithm("period smarter".to_string()))
+    );
+    assert_eq!(
+        Registry::builtin().select(&["period z", "period z"]).err(),
+        Some(Error::DuplicateAlgorithm("period z".to_string()))
+    );
+
+    let mut registry = Registry::new();
+    registry.register(PERIOD_SMART);
+    assert_eq!(
+        registry.try_register(PERIOD_SMART),
+        Err(Error::DuplicateAlgorithm("period smart".to_string()))
+    );
+}
+
+#[test]
+fn test_border_array() {
+    let input: InputString = "abcabcaabcabcaabc".try_into().unwrap();
+    let expected = vec![0, 0, 0, 1, 2, 3, 4, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    assert_eq!(border_array(&input[..]), expected);
+}
+
+#[test]
+fn test_border_chain() {
+    let input: InputString = "abaababaab".try_into().unwrap();
+    let borders = BorderArray::new(&input[..]);
+    assert_eq!(borders.longest_border(9), 5);
+    assert_eq!(borders.borders(9).collect::<Vec<_>>(), vec![5, 2]);
+    assert_eq!(borders.borders(0).count(), 0);
+    assert_eq!(borders.period(9), 5);
+}
+
+#[test]
+fn test_prefix_periods() {
+    let input: InputString = "abcabcaabcabcaabc".try_into().unwrap();
+    let periods = prefix_periods(&input[..]);
+    for i in 0..input.len() {
+        let prefix = InputString(input[..=i].to_vec());
+        test(prefix, periods[i]);
+    }
+}
+
+#[test]
+fn test_all_periods() {
+    let inputs = ["abaababaab", "abcabcabca", "aabaabaa", "abc", "a"];
+    for input in inputs {
+        let input: InputString = input.try_into().unwrap();
+        let n = input.len();
+        let expected = (1..=n)
+            .filter(|&p| input[..n - p] == input[p..])
+            .collect::<Vec<_>>();
+        let periods = all_periods(&input[..]);
+        assert_eq!(periods.iter().collect::<Vec<_>>(), expected);
+        assert_eq!(periods.len(), expected.len());
+        assert_eq!(periods.min(), Some(expected[0]));
+    }
+}
+
+#[test]
+fn test_all_periods_compact() {
+    let input = InputString(vec![b'a'; 500_000]);
+    let periods = all_periods(&input[..]);
+    assert_eq!(periods.progressions().len(), 1);
+    assert_eq!(periods.len(), 500_000);
+    assert!(periods.contains(123_456));
+}
+
+#[test]
+fn test_z_array() {
+    let input: InputString = "abcabcaabcabcaabc".try_into().unwrap();
+    let n = input.len();
+    let z = z_array(&input[..]);
+    for i in 0..n {
+        let expected = (0..n - i).take_while(|&j| input[j] == input[i + j]).count();
+        assert_eq!(z[i], expected);
+    }
+}
+
+#[test]
+fn test_maximal_suffix() {
+    let input: InputString = "abcbcbab".try_into().unwrap();
+    let lexicographic = maximal_suffix(&input[..], SuffixOrder::Lexicographic);
+    assert_eq!((lexicographic.position, lexicographic.period), (2, 6));
+    let reversed = maximal_suffix(&input[..], SuffixOrder::Reversed);
+    assert_eq!((reversed.position, reversed.period), (0, 6));
+}
+
+/// Test the critical factorization theorem on every string of length up to 10 over a binary
+/// alphabet
+#[test]
+fn test_critical_factorization() {
+    for input in all_strings(b"ab", 10) {
+        let input = InputString(input);
+        let period = period_naive1(&input[..]);
+        let factorization = critical_factorization(&input[..]);
+        assert!(factorization.position < period);
+        assert_eq!(local_period(&input[..], factorization.position), period);
+    }
+
+    // the empty string has period 0 and critical position 0
+    let empty: [u8; 0] = [];
+    assert_eq!(critical_factorization(&empty).position, 0);
+    assert_eq!(local_period(&empty, 0), 0);
+}
+
+#[test]
+fn test_primitive_root() {
+    let input: InputString = "abcabcabc".try_into().unwrap();
+    assert!(!is_primitive(&input));
+    assert_eq!(primitive_root(&input), (&b"abc"[..], 3));
+
+    let input: InputString = "abcabcab".try_into().unwrap();
+    assert!(is_primitive(&input));
+    assert_eq!(primitive_root(&input), (&b"abcabcab"[..], 1));
+
+    let input: InputString = "aaaa".try_into().unwrap();
+    assert_eq!(primitive_root(&input), (&b"a"[..], 4));
+
+    assert!(!is_primitive(&InputString(Vec::new())));
+    assert_eq!(primitive_root(&InputString(Vec::new())), (&b""[..], 0));
+    assert_eq!(
+        try_primitive_root(&InputString(Vec::new())),
+        Err(Error::EmptyString)
+    );
+    assert_eq!(try_primitive_root(&input), Ok((&b"a"[..], 4)));
+}
+
+#[test]
+fn test_generic_elements() {
+    let code_points = "αβαβα".chars().map(u32::from).collect::<Vec<_>>();
+    assert_eq!(period_naive1(&code_points), 2);
+    assert_eq!(period_naive2(&code_points), 2);
+    assert_eq!(period_smart(&code_points), 2);
+
+    let tokens = ["let", "x", "=", "1", ";", "let", "x", "="];
+    assert_eq!(period_naive1(&tokens), 5);
+    assert_eq!(period_naive2(&tokens), 5);
+    assert_eq!(period_smart(&tokens), 5);
+}
@@ -0,0 +1,31 @@
+// Helpers shared by the integration tests, not every test uses all of them
+#![allow(dead_code)]
+
+/// Returns every string of the given length over the alphabet.
+///
+/// # Arguments
+///
+/// * `alphabet` - The characters of the strings
+/// * `length` - The length of the strings
+pub fn strings_of_length(alphabet: &[u8], length: usize) -> impl Iterator<Item = Vec<u8>> + '_ {
+    let count = alphabet.len().pow(length as u32);
+    (0..count).map(move |mut code| {
+        let mut s = Vec::with_capacity(length);
+        for _ in 0..length {
+            s.push(alphabet[code % alphabet.len()]);
+            code /= alphabet.len();
+        }
+        s
+    })
+}
+
+/// Returns every non empty string of length up to `max_len` over the alphabet, by increasing
+/// length.
+///
+/// # Arguments
+///
+/// * `alphabet` - The characters of the strings
+/// * `max_len` - The maximum length of the strings
+pub fn all_strings(alphabet: &[u8], max_len: usize) -> impl Iterator<Item = Vec<u8>> + '_ {
+    (1..=max_len).flat_map(move |length| strings_of_length(alphabet, length))
+}
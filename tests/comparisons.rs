@@ -0,0 +1,121 @@
+mod common;
+
+use common::{all_strings, strings_of_length};
+use fractional_period::{
+    algorithms::{period_naive1, period_naive2, period_smart, period_z, prefix_periods},
+    comparisons::{count_comparisons, COMPARISONS_NAIVE1, COMPARISONS_SMART},
+    input::{InputString, StringGen, StringGenFunction},
+    simd::period_naive_early_exit,
+};
+
+/// The instrumented algorithms must return the same period as the original ones, and the border
+/// algorithm must perform at most 3n - 4 comparisons
+#[test]
+fn test_comparisons_bound() {
+    for input in all_strings(b"ab", 12).filter(|s| s.len() >= 2) {
+        let (period, comparisons) = count_comparisons(&input, |s| period_smart(s));
+        assert_eq!(period, period_smart(&input));
+        assert!(
+            comparisons <= 3 * input.len() - 4,
+            "{comparisons} comparisons"
+        );
+        assert_eq!(
+            count_comparisons(&input, |s| period_naive1(s)).0,
+            period_naive1(&input)
+        );
+        assert_eq!(
+            count_comparisons(&input, |s| period_naive2(s)).0,
+            period_naive2(&input)
+        );
+    }
+}
+
+#[test]
+fn test_comparisons_examples() {
+    // A unary string is compared once for each of the n - 1 positions of the shift by 1, while
+    // the border algorithm compares every new character twice
+    let input = InputString(vec![b'a'; 10]);
+    assert_eq!((COMPARISONS_NAIVE1.function)(&input), 9);
+    assert_eq!((COMPARISONS_SMART.function)(&input), 18);
+
+    // No comparison is needed for the empty string and for a single character
+    assert_eq!((COMPARISONS_NAIVE1.function)(&InputString(Vec::new())), 0);
+    assert_eq!((COMPARISONS_SMART.function)(&InputString(vec![b'a'])), 0);
+
+    // Every shift of "ab...b" fails at the first comparison
+    let input: InputString = "abbbb".try_into().unwrap();
+    assert_eq!((COMPARISONS_NAIVE1.function)(&input), 4);
+}
+
+/// Every hand-crafted worst case makes the algorithms it targets perform the maximum number of
+/// comparisons among the binary strings of the same length
+#[test]
+fn test_worst_cases() {
+    let worst_case =
+        |function, n| StringGen::new(function, vec![b'a', b'b']).create_random_string(n);
+    // the number of comparisons performed by an algorithm on a string
+    type Comparisons = fn(&[u8]) -> usize;
+    let algorithms: [(&str, Comparisons, StringGenFunction); 6] = [
+        (
+            "naive 1",
+            |s| count_comparisons(s, |s| period_naive1(s)).1,
+            StringGenFunction::CreateRandomString4,
+        ),
+        (
+            "naive 2",
+            |s| count_comparisons(s, |s| period_naive2(s)).1,
+            StringGenFunction::CreateRandomString4,
+        ),
+        (
+            "smart",
+            |s| count_comparisons(s, |s| period_smart(s)).1,
+            StringGenFunction::CreateRandomString4,
+        ),
+        (
+            "prefixes",
+            |s| count_comparisons(s, |s| prefix_periods(s)).1,
+            StringGenFunction::CreateRandomString4,
+        ),
+        (
+            "z",
+            |s| count_comparisons(s, |s| period_z(s)).1,
+            StringGenFunction::CreateRandomString4,
+        ),
+        (
+            "naive early exit",
+            |s| count_comparisons(s, |s| period_naive_early_exit(s)).1,
+            StringGenFunction::WorstCaseNaiveSimd,
+        ),
+    ];
+    for length in 3..=12 {
+        for (name, comparisons, function) in algorithms {
+            let maximum = strings_of_length(b"ab", length)
+                .map(|s| comparisons(&s))
+                .max()
+                .unwrap();
+            assert_eq!(
+                comparisons(&worst_case(function, length)),
+                maximum,
+                "{name}, length {length}"
+            );
+        }
+    }
+
+    // the number of comparisons of the worst cases
+    for n in [4, 10, 100, 1000] {
+        let input = InputString(worst_case(StringGenFunction::CreateRandomString4, n));
+        assert_eq!((COMPARISONS_NAIVE1.function)(&input), n * (n - 1) / 2);
+        assert_eq!((COMPARISONS_SMART.function)(&input), 3 * n - 4);
+        let input = worst_case(StringGenFunction::WorstCaseNaiveSimd, n);
+        assert_eq!(
+            count_comparisons(&input, |s| period_naive_early_exit(s)).1,
+            n * (n - 1) / 2
+        );
+    }
+
+    assert_eq!(
+        "WorstCaseNaiveSimd".parse(),
+        Ok(StringGenFunction::WorstCaseNaiveSimd)
+    );
+    assert!("WorstCaseNaive".parse::<StringGenFunction>().is_err());
+}
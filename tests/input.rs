@@ -0,0 +1,339 @@
+mod common;
+
+use chrono_probe::input::Input;
+use common::strings_of_length;
+use std::collections::HashSet;
+
+use fractional_period::algorithms::{period_naive1, period_smart, try_period_smart, Algorithm};
+use fractional_period::error::Error;
+use fractional_period::input::{
+    GenSource, InputString, StringGen, StringGenFunction, TargetPeriod, UnicodeString,
+    UnicodeStringGen,
+};
+use fractional_period::runs::{contains_power, runs};
+
+#[test]
+fn test_unicode_period() {
+    let input = UnicodeString::from("αβαβα");
+    assert_eq!(input.get_size(), 5);
+    assert_eq!(period_smart(&input[..]), 2);
+
+    let algorithm: Algorithm<UnicodeString> = Algorithm {
+        name: "unicode period smart",
+        function: |s| period_smart(&s[..]),
+    };
+    assert_eq!((algorithm.function)(&"日本日本日".into()), 2);
+}
+
+#[test]
+fn test_unicode_generation() {
+    let char_set = vec!['α', 'β', 'γ'];
+    for function in [
+        StringGenFunction::CreateRandomString1,
+        StringGenFunction::CreateRandomString2,
+        StringGenFunction::CreateRandomString3,
+        StringGenFunction::CreateRandomString4,
+    ] {
+        let string_gen = UnicodeStringGen::new(function, char_set.clone());
+        let input = UnicodeString::generate_input(100, &string_gen);
+        assert_eq!(input.get_size(), 100);
+    }
+
+    let string_gen =
+        UnicodeStringGen::new(StringGenFunction::CreateRandomString1, char_set.clone());
+    let input = string_gen.create_random_string(1000);
+    assert!(input.iter().all(|c| char_set.contains(c)));
+
+    let string_gen = UnicodeStringGen::new(StringGenFunction::CreateRandomString4, char_set);
+    assert_eq!(string_gen.create_random_string(4), vec!['α', 'α', 'α', 'β']);
+}
+
+#[test]
+#[should_panic]
+fn test_unicode_repetitions() {
+    UnicodeStringGen::new(StringGenFunction::CreateRandomString1, vec!['α', 'β', 'α']);
+}
+
+#[test]
+fn test_errors() {
+    let input = InputString::try_from("abγd");
+    assert_eq!(
+        input.err(),
+        Some(Error::NonAsciiByte {
+            position: 2,
+            byte: 0xce
+        })
+    );
+
+    let string_gen = StringGen::try_new(StringGenFunction::CreateRandomString1, vec![]);
+    assert_eq!(string_gen.err(), Some(Error::EmptyAlphabet));
+
+    let string_gen = StringGen::try_new(StringGenFunction::CreateRandomString1, vec![b'a', b'a']);
+    assert_eq!(string_gen.err(), Some(Error::DuplicateSymbol('a')));
+
+    let string_gen = StringGen::try_new(StringGenFunction::CreateRandomString1, vec![b'a', 200]);
+    assert_eq!(string_gen.err(), Some(Error::NonAsciiSymbol('\u{c8}')));
+
+    let string_gen = StringGen::try_new(StringGenFunction::CreateRandomString4, vec![b'a']);
+    assert_eq!(
+        string_gen.err(),
+        Some(Error::AlphabetTooSmall {
+            required: 2,
+            actual: 1
+        })
+    );
+
+    let string_gen = StringGen::try_new(StringGenFunction::CreateRandomString3, (0..128).collect());
+    assert_eq!(
+        string_gen.err(),
+        Some(Error::AlphabetTooLarge {
+            maximum: 127,
+            actual: 128
+        })
+    );
+
+    let string_gen = StringGen::new(StringGenFunction::CreateRandomString1, vec![b'a', b'b']);
+    assert_eq!(
+        string_gen.try_create_random_string(0),
+        Err(Error::ZeroLength)
+    );
+    assert_eq!(string_gen.try_create_random_string(3).unwrap().len(), 3);
+
+    assert_eq!(try_period_smart::<u8>(&[]), Err(Error::EmptyString));
+    assert_eq!(try_period_smart(b"abab"), Ok(2));
+}
+
+#[test]
+fn test_seed() {
+    let char_set = vec![b'a', b'b', b'c'];
+    for function in StringGenFunction::ALL {
+        let string_gen = StringGen::new(function, char_set.clone()).with_seed(42);
+        let clone = string_gen.clone();
+        let same_seed = StringGen::new(function, char_set.clone()).with_seed(42);
+
+        // the k-th string of a given length only depends on the seed, whatever the strings of
+        // other lengths generated in between
+        let first = string_gen.create_random_string(1000);
+        let second = string_gen.create_random_string(1000);
+        same_seed.create_random_string(10);
+        assert_eq!(same_seed.create_random_string(1000), first);
+        assert_eq!(same_seed.create_random_string(1000), second);
+        assert_eq!(clone.create_random_string(1000), first);
+        if matches!(
+            function,
+            StringGenFunction::CreateRandomString1
+                | StringGenFunction::CreateRandomString2
+                | StringGenFunction::CreateRandomString3
+                | StringGenFunction::Sturmian
+        ) {
+            assert_ne!(first, second);
+        }
+    }
+
+    let string_gen = StringGen::new(StringGenFunction::CreateRandomString1, char_set.clone());
+    assert_ne!(
+        string_gen.clone().with_seed(1).create_random_string(1000),
+        string_gen.with_seed(2).create_random_string(1000)
+    );
+
+    let string_gen = UnicodeStringGen::new(StringGenFunction::CreateRandomString1, vec!['α', 'β']);
+    assert_eq!(
+        string_gen.clone().with_seed(7).create_random_string(100),
+        string_gen.with_seed(7).create_random_string(100)
+    );
+}
+
+/// Generate the prefix of length n of a word with a binary or ternary character set
+fn word(function: StringGenFunction, n: usize) -> Vec<u8> {
+    StringGen::new(function, vec![b'a', b'b', b'c'])
+        .with_seed(0)
+        .create_random_string(n)
+}
+
+#[test]
+fn test_morphic_words() {
+    assert_eq!(
+        word(StringGenFunction::Fibonacci, 20),
+        b"abaababaabaababaabab"
+    );
+    assert_eq!(
+        word(StringGenFunction::ThueMorse, 20),
+        b"abbabaabbaababbabaab"
+    );
+    assert_eq!(
+        word(StringGenFunction::PeriodDoubling, 20),
+        b"abaaabababaaabaaabaa"
+    );
+    assert_eq!(
+        word(StringGenFunction::Tribonacci, 20),
+        b"abacabaabacababacaba"
+    );
+    assert_eq!(word(StringGenFunction::Fibonacci, 1), b"a");
+
+    // the Fibonacci word of length F(k) has period F(k - 1)
+    let (mut previous, mut length) = (2, 3);
+    while length < 10_000 {
+        assert_eq!(
+            period_smart(&word(StringGenFunction::Fibonacci, length)),
+            previous
+        );
+        (previous, length) = (length, previous + length);
+    }
+
+    // the Fibonacci word contains cubes but no fourth powers, the Thue–Morse word is overlap-free
+    let fibonacci = word(StringGenFunction::Fibonacci, 2000);
+    assert!(contains_power(&fibonacci, 3));
+    assert!(!contains_power(&fibonacci, 4));
+    let thue_morse = word(StringGenFunction::ThueMorse, 2000);
+    assert!(runs(&thue_morse)
+        .iter()
+        .all(|run| run.len() <= 2 * run.period));
+
+    // the Tribonacci word needs three characters
+    assert_eq!(
+        StringGen::try_new(StringGenFunction::Tribonacci, vec![b'a', b'b']).err(),
+        Some(Error::AlphabetTooSmall {
+            required: 3,
+            actual: 2
+        })
+    );
+}
+
+#[test]
+fn test_sturmian_words() {
+    let string_gen = StringGen::new(StringGenFunction::Sturmian, vec![b'a', b'b']).with_seed(3);
+    for _ in 0..10 {
+        let s = string_gen.create_random_string(500);
+        // Sturmian words are balanced: factors of the same length contain the same number of b's,
+        // up to one
+        for length in 1..50 {
+            let counts = s
+                .windows(length)
+                .map(|factor| factor.iter().filter(|&&c| c == b'b').count())
+                .collect::<Vec<_>>();
+            assert!(counts.iter().max().unwrap() - counts.iter().min().unwrap() <= 1);
+        }
+    }
+}
+
+#[test]
+fn test_exact_period() {
+    for char_set in [vec![b'a', b'b'], vec![b'a', b'b', b'c']] {
+        for n in 1..=40 {
+            for p in 1..=n {
+                let string_gen =
+                    StringGen::from_period(char_set.clone(), TargetPeriod::Fixed(p)).with_seed(7);
+                for _ in 0..5 {
+                    let s = string_gen.create_random_string(n);
+                    assert_eq!(s.len(), n);
+                    assert_eq!(period_smart(&s), p, "{:?}", String::from_utf8_lossy(&s));
+                    assert_eq!(period_naive1(&s), p);
+                }
+            }
+        }
+    }
+
+    // long strings with long periods
+    let string_gen = StringGen::from_period(vec![b'a', b'b'], TargetPeriod::Fixed(1000));
+    for n in [1000, 1500, 1999, 2000, 10000] {
+        assert_eq!(period_smart(&string_gen.create_random_string(n)), 1000);
+    }
+}
+
+#[test]
+fn test_exact_period_uniformity() {
+    // every binary string of length 6 with the target period is generated
+    let n = 6;
+    for p in 1..=n {
+        let expected = strings_of_length(b"ab", n)
+            .filter(|s| period_smart(s) == p)
+            .collect::<HashSet<_>>();
+        let string_gen =
+            StringGen::from_period(vec![b'a', b'b'], TargetPeriod::Fixed(p)).with_seed(11);
+        let generated = (0..2000)
+            .map(|_| string_gen.create_random_string(n))
+            .collect::<HashSet<_>>();
+        assert_eq!(generated, expected);
+    }
+}
+
+#[test]
+fn test_period_distributions() {
+    let char_set = vec![b'a', b'b', b'c'];
+    let string_gen =
+        StringGen::from_period(char_set.clone(), TargetPeriod::Uniform(3..=10)).with_seed(5);
+    let mut periods = HashSet::new();
+    for n in 3..=20 {
+        for _ in 0..20 {
+            let p = period_smart(&string_gen.create_random_string(n));
+            assert!((3..=n.min(10)).contains(&p));
+            periods.insert(p);
+        }
+    }
+    assert_eq!(periods, (3..=10).collect());
+
+    let string_gen = StringGen::from_period(char_set, TargetPeriod::Fraction(0.5)).with_seed(5);
+    for n in 1..=50 {
+        assert_eq!(
+            period_smart(&string_gen.create_random_string(n)),
+            n.div_ceil(2)
+        );
+    }
+}
+
+#[test]
+fn test_period_errors() {
+    let char_set = vec![b'a', b'b'];
+    for period in [
+        TargetPeriod::Fixed(0),
+        TargetPeriod::Uniform(0..=3),
+        #[allow(clippy::reversed_empty_ranges)]
+        TargetPeriod::Uniform(5..=4),
+        TargetPeriod::Fraction(0.0),
+        TargetPeriod::Fraction(1.5),
+        TargetPeriod::Fraction(f64::NAN),
+    ] {
+        assert_eq!(
+            StringGen::try_from_period(char_set.clone(), period).err(),
+            Some(Error::InvalidPeriod)
+        );
+    }
+    assert_eq!(
+        StringGen::try_from_period(vec![b'a'], TargetPeriod::Fixed(1)).err(),
+        Some(Error::AlphabetTooSmall {
+            required: 2,
+            actual: 1
+        })
+    );
+
+    let string_gen = StringGen::from_period(char_set.clone(), TargetPeriod::Fixed(10));
+    assert_eq!(
+        string_gen.try_create_random_string(5),
+        Err(Error::PeriodTooLong {
+            period: 10,
+            length: 5
+        })
+    );
+    let string_gen = StringGen::from_period(char_set, TargetPeriod::Uniform(8..=10));
+    assert_eq!(
+        string_gen.try_create_random_string(5),
+        Err(Error::PeriodTooLong {
+            period: 8,
+            length: 5
+        })
+    );
+}
+
+#[test]
+fn test_gen_source() {
+    let mut string_gen = StringGen::from_period(vec![b'a', b'b'], TargetPeriod::Fixed(3));
+    assert!(matches!(
+        string_gen.source,
+        GenSource::Period(TargetPeriod::Fixed(3))
+    ));
+
+    // replacing the source changes the generated strings
+    string_gen.source =
+        StringGen::new(StringGenFunction::CreateRandomString4, vec![b'a', b'b']).source;
+    assert_eq!(string_gen.create_random_string(5), b"aaaab");
+}
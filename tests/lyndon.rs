@@ -0,0 +1,59 @@
+mod common;
+
+use common::all_strings;
+use fractional_period::input::InputString;
+use fractional_period::lyndon::{
+    is_lyndon_word, lyndon_factorization, minimal_rotation, LYNDON_FACTORS,
+};
+
+/// Returns true if the string is strictly smaller than all its proper suffixes
+fn naive_is_lyndon_word(s: &[u8]) -> bool {
+    !s.is_empty() && (1..s.len()).all(|i| s < &s[i..])
+}
+
+/// Test that the Lyndon factorization of the string is made of non increasing Lyndon words
+///
+/// # Arguments
+///
+/// * `s` - The string to be factorized
+fn test(s: &[u8]) {
+    let ends = lyndon_factorization(s);
+    let mut start = 0;
+    let mut previous: Option<&[u8]> = None;
+    for &end in ends.iter() {
+        let factor = &s[start..end];
+        assert!(naive_is_lyndon_word(factor));
+        if let Some(previous) = previous {
+            assert!(previous >= factor);
+        }
+        previous = Some(factor);
+        start = end;
+    }
+    assert_eq!(start, s.len());
+    assert_eq!(is_lyndon_word(s), naive_is_lyndon_word(s));
+
+    let n = s.len();
+    let rotation = |i: usize| [&s[i..], &s[..i]].concat();
+    let expected = (0..n).min_by_key(|&i| rotation(i)).unwrap_or(0);
+    assert_eq!(minimal_rotation(s), expected);
+}
+
+#[test]
+fn test_examples() {
+    let input: InputString = "abaababaab".try_into().unwrap();
+    assert_eq!(lyndon_factorization(&input), vec![2, 7, 10]);
+    assert_eq!((LYNDON_FACTORS.function)(&input), 3);
+
+    let input: InputString = "bbaa".try_into().unwrap();
+    assert_eq!(lyndon_factorization(&input), vec![1, 2, 3, 4]);
+
+    assert!(lyndon_factorization(b"").is_empty());
+}
+
+/// Test every string of length up to 12 over a binary alphabet
+#[test]
+fn test_cross_check() {
+    for input in all_strings(b"ab", 12) {
+        test(&input);
+    }
+}
@@ -0,0 +1,155 @@
+use fractional_period::{
+    algorithms::period_smart,
+    error::Error,
+    input::StringGen,
+    markov::{MarkovModel, Transition},
+};
+use rand::{rngs::StdRng, SeedableRng};
+
+#[test]
+fn test_frequencies() {
+    let model = MarkovModel::from_frequencies(&[(b'a', 3.0), (b'b', 1.0), (b'c', 0.0)]).unwrap();
+    assert_eq!(model.order(), 0);
+    assert_eq!(model.symbols(), b"ab");
+
+    let s = model.generate(100_000, &mut StdRng::seed_from_u64(0));
+    assert_eq!(s.len(), 100_000);
+    let a = s.iter().filter(|&&c| c == b'a').count();
+    assert!((74_000..76_000).contains(&a), "{a} occurrences of a");
+    assert!(!s.contains(&b'c'));
+}
+
+#[test]
+fn test_training() {
+    // a deterministic chain generates the rotations of the sample
+    let model = MarkovModel::train(b"abcabcabc", 2).unwrap();
+    let mut rng = StdRng::seed_from_u64(1);
+    for n in 1..50 {
+        let s = model.generate(n, &mut rng);
+        assert_eq!(s.len(), n);
+        assert_eq!(period_smart(&s), n.min(3));
+    }
+
+    // every factor of length order + 1 of a generated string is a factor of the circular sample
+    let sample = b"GATTACAGATTACCAGGCATTAGACCCATAGGATTACA";
+    let circular = [&sample[..], &sample[..]].concat();
+    for order in 0..4 {
+        let model = MarkovModel::train(sample, order).unwrap();
+        assert_eq!(model.symbols(), b"ACGT");
+        let s = model.generate(1000, &mut rng);
+        for factor in s.windows(order + 1) {
+            assert!(circular.windows(order + 1).any(|window| window == factor));
+        }
+    }
+}
+
+#[test]
+fn test_transitions() {
+    // the factors of length 3 of the strings only contain one b
+    let transitions: [Transition; 3] = [
+        (b"aa", &[(b'a', 1.0), (b'b', 1.0)]),
+        (b"ab", &[(b'a', 1.0)]),
+        // the character c has weight 0 so the context ac is never reached
+        (b"ba", &[(b'a', 1.0), (b'c', 0.0)]),
+    ];
+    let model = MarkovModel::from_transitions(2, &transitions).unwrap();
+    assert_eq!(model.order(), 2);
+    assert_eq!(model.symbols(), b"ab");
+    let mut rng = StdRng::seed_from_u64(2);
+    for n in 1..100 {
+        let s = model.generate(n, &mut rng);
+        assert_eq!(s.len(), n);
+        for factor in s.windows(3) {
+            assert!(factor.iter().filter(|&&c| c == b'b').count() <= 1, "{s:?}");
+        }
+    }
+
+    assert_eq!(
+        MarkovModel::from_transitions(1, &[]).err(),
+        Some(Error::EmptyAlphabet)
+    );
+    assert_eq!(
+        MarkovModel::from_transitions(1, &[(b"ab", &[(b'a', 1.0)])]).err(),
+        Some(Error::ContextLength {
+            order: 1,
+            actual: 2
+        })
+    );
+    assert_eq!(
+        MarkovModel::from_transitions(1, &[(b"a", &[(b'a', 1.0)]), (b"a", &[(b'a', 2.0)])]).err(),
+        Some(Error::DuplicateContext("a".to_string()))
+    );
+    assert_eq!(
+        MarkovModel::from_transitions(1, &[(b"a", &[(b'a', -1.0)])]).err(),
+        Some(Error::InvalidFrequencies)
+    );
+    assert_eq!(
+        MarkovModel::from_transitions(2, &transitions[..2]).err(),
+        Some(Error::MissingContext("ba".to_string()))
+    );
+}
+
+#[test]
+fn test_string_gen() {
+    let model = MarkovModel::train(b"the quick brown fox jumps over the lazy dog", 3).unwrap();
+    let string_gen = StringGen::from_model(model).with_seed(42);
+    let same_seed = string_gen.clone();
+    let s = string_gen.create_random_string(500);
+    assert_eq!(s.len(), 500);
+    assert!(s.iter().all(|c| string_gen.char_set.contains(c)));
+    assert_eq!(same_seed.create_random_string(500), s);
+}
+
+#[test]
+fn test_from_file() {
+    let path = std::env::temp_dir().join("fractional_period_markov_sample.txt");
+    std::fs::write(&path, "ACGTTGCAACGT").unwrap();
+    let model = MarkovModel::from_file(&path, 1).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(model.order(), 1);
+    assert_eq!(model.symbols(), b"ACGT");
+
+    assert!(matches!(
+        MarkovModel::from_file(&path, 1),
+        Err(Error::Io(_))
+    ));
+}
+
+#[test]
+fn test_errors() {
+    assert_eq!(
+        MarkovModel::from_frequencies(&[]).err(),
+        Some(Error::EmptyAlphabet)
+    );
+    assert_eq!(
+        MarkovModel::from_frequencies(&[(b'a', 1.0), (b'a', 2.0)]).err(),
+        Some(Error::DuplicateSymbol('a'))
+    );
+    assert_eq!(
+        MarkovModel::from_frequencies(&[(b'a', 1.0), (b'b', -1.0)]).err(),
+        Some(Error::InvalidFrequencies)
+    );
+    assert_eq!(
+        MarkovModel::from_frequencies(&[(b'a', 0.0)]).err(),
+        Some(Error::InvalidFrequencies)
+    );
+    assert_eq!(
+        MarkovModel::from_frequencies(&[(b'a', f64::NAN)]).err(),
+        Some(Error::InvalidFrequencies)
+    );
+    assert_eq!(MarkovModel::train(b"", 0).err(), Some(Error::EmptyString));
+    assert_eq!(
+        MarkovModel::train(b"abc", 3).err(),
+        Some(Error::SampleTooShort {
+            required: 4,
+            actual: 3
+        })
+    );
+    assert_eq!(
+        MarkovModel::train("abγ".as_bytes(), 1).err(),
+        Some(Error::NonAsciiByte {
+            position: 2,
+            byte: 0xce
+        })
+    );
+}
@@ -0,0 +1,62 @@
+mod common;
+
+use common::{all_strings, strings_of_length};
+use fractional_period::input::InputString;
+use fractional_period::matching::{Kmp, TwoWay, MATCHING_KMP, MATCHING_TWO_WAY};
+
+/// Finds all the occurrences of the pattern in the text by comparing it at every position
+fn naive_find_all(pattern: &[u8], text: &[u8]) -> Vec<usize> {
+    if pattern.len() > text.len() {
+        return Vec::new();
+    }
+    (0..=text.len() - pattern.len())
+        .filter(|&i| text[i..i + pattern.len()] == *pattern)
+        .collect()
+}
+
+/// Test the matching algorithms
+///
+/// # Arguments
+///
+/// * `pattern` - The pattern to be searched
+/// * `text` - The text to be searched
+fn test(pattern: &[u8], text: &[u8]) {
+    let expected = naive_find_all(pattern, text);
+    assert_eq!(
+        Kmp::new(pattern).find_all(text).collect::<Vec<_>>(),
+        expected
+    );
+    assert_eq!(
+        TwoWay::new(pattern).find_all(text).collect::<Vec<_>>(),
+        expected
+    );
+    assert_eq!(Kmp::new(pattern).find(text), expected.first().copied());
+    assert_eq!(TwoWay::new(pattern).find(text), expected.first().copied());
+}
+
+#[test]
+fn test_examples() {
+    test(b"abab", b"abababab");
+    test(b"aaa", b"aaaaa");
+    test(b"abc", b"ababcabcab");
+    test(b"baab", b"abaababaabaab");
+    test(b"abcd", b"abc");
+    test(b"", b"abc");
+}
+
+/// Test every pattern of length up to 6 on every text of length 10 over a binary alphabet
+#[test]
+fn test_cross_check() {
+    for text in strings_of_length(b"ab", 10) {
+        for pattern in all_strings(b"ab", 6) {
+            test(&pattern, &text);
+        }
+    }
+}
+
+#[test]
+fn test_prefix_occurrences() {
+    let input: InputString = "abaababaabaab".try_into().unwrap();
+    assert_eq!((MATCHING_KMP.function)(&input), 3);
+    assert_eq!((MATCHING_TWO_WAY.function)(&input), 3);
+}
@@ -0,0 +1,111 @@
+mod common;
+
+use common::all_strings;
+use fractional_period::input::{InputString, StringGen, StringGenFunction};
+use fractional_period::runs::{
+    contains_power, contains_square, primitively_rooted_squares, runs, Run, Square, RUNS,
+};
+
+/// Computes all the runs of the string by scanning, for every period, the maximal substrings
+/// having that period
+fn naive_runs(s: &[u8]) -> Vec<Run> {
+    let n = s.len();
+    let mut runs = Vec::new();
+    for period in 1..=n / 2 {
+        let mut start = 0;
+        while start + period < n {
+            let mut end = start;
+            while end + period < n && s[end] == s[end + period] {
+                end += 1;
+            }
+            let run = Run {
+                start,
+                end: end + period,
+                period,
+            };
+            // the period must be the minimum one of the substring
+            let minimum = (1..=period)
+                .find(|&p| (run.start..run.end - p).all(|i| s[i] == s[i + p]))
+                .unwrap();
+            if end - start >= period && minimum == period {
+                runs.push(run);
+            }
+            start = end + 1;
+        }
+    }
+    runs.sort_unstable();
+    runs
+}
+
+/// Computes all the occurrences of primitively rooted squares by checking every substring
+fn naive_primitively_rooted_squares(s: &[u8]) -> Vec<Square> {
+    let n = s.len();
+    let mut squares = Vec::new();
+    for start in 0..n {
+        for period in 1..=(n - start) / 2 {
+            let root = &s[start..start + period];
+            let primitive = (1..period)
+                .filter(|d| period % d == 0)
+                .all(|d| (d..period).any(|i| root[i] != root[i - d]));
+            if root == &s[start + period..start + 2 * period] && primitive {
+                squares.push(Square { start, period });
+            }
+        }
+    }
+    squares
+}
+
+#[test]
+fn test_examples() {
+    let input: InputString = "abaababaabaab".try_into().unwrap();
+    let expected = naive_runs(&input);
+    assert_eq!(runs(&input), expected);
+    assert_eq!((RUNS.function)(&input), expected.len());
+
+    let input: InputString = "aaaa".try_into().unwrap();
+    let run = runs(&input)[0];
+    assert_eq!(
+        run,
+        Run {
+            start: 0,
+            end: 4,
+            period: 1
+        }
+    );
+    assert_eq!(run.exponent(), 4.0);
+
+    assert!(runs(b"").is_empty());
+    assert!(runs(b"abc").is_empty());
+}
+
+/// Test every string of length up to 12 over a binary alphabet and of length up to 8 over a
+/// ternary alphabet
+#[test]
+fn test_cross_check() {
+    for (alphabet, max_length) in [(&b"ab"[..], 12), (b"abc", 8)] {
+        for input in all_strings(alphabet, max_length) {
+            assert_eq!(runs(&input), naive_runs(&input));
+            assert_eq!(
+                primitively_rooted_squares(&input),
+                naive_primitively_rooted_squares(&input)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_powers() {
+    let input: InputString = "abcabcab".try_into().unwrap();
+    assert!(contains_square(&input));
+    assert!(!contains_power(&input, 3));
+
+    // square free
+    let input: InputString = "abcacbabcbac".try_into().unwrap();
+    assert!(!contains_square(&input));
+
+    // a^(n-1)b contains a (n-1)-th power
+    let string_gen = StringGen::new(StringGenFunction::CreateRandomString4, vec![b'a', b'b']);
+    let input = string_gen.create_random_string(100);
+    assert!(contains_power(&input, 99));
+    assert!(!contains_power(&input, 100));
+}
@@ -0,0 +1,31 @@
+use fractional_period::{algorithms::period_naive2, simd::period_naive_simd};
+
+/// Compare the SIMD algorithm with the naive one on periodic strings long enough to use the
+/// vector instructions, with a mismatch at every possible position
+#[test]
+fn test_simd_mismatches() {
+    for period in [1, 3, 16, 17, 31, 32, 33] {
+        for n in [period, 40, 64, 65, 100, 150] {
+            let periodic = (0..n)
+                .map(|i| b'a' + (i % period % 3) as u8)
+                .collect::<Vec<_>>();
+            assert_eq!(period_naive_simd(&periodic), period_naive2(&periodic));
+            for position in 0..n {
+                let mut input = periodic.clone();
+                input[position] = b'z';
+                assert_eq!(period_naive_simd(&input), period_naive2(&input));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_simd_edge_cases() {
+    assert_eq!(period_naive_simd(b""), 0);
+    assert_eq!(period_naive_simd(b"a"), 1);
+    assert_eq!(period_naive_simd(&[b'a'; 1000]), 1);
+
+    let mut input = vec![b'a'; 1000];
+    input[999] = b'b';
+    assert_eq!(period_naive_simd(&input), 1000);
+}